@@ -0,0 +1,72 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// One line of the audit log (JSON Lines) per request sent to an AI provider
+#[derive(Debug, Serialize)]
+pub(crate) struct AuditEntry {
+    pub(crate) timestamp: u64,
+    pub(crate) command: String,
+    pub(crate) provider: String,
+    pub(crate) model: String,
+    pub(crate) system_bytes: usize,
+    pub(crate) user_bytes: usize,
+    pub(crate) response_bytes: usize,
+    /// SHA-256 of exactly what was sent (after redaction)
+    pub(crate) payload_sha256: String,
+    pub(crate) redactions: usize,
+    pub(crate) ok: bool,
+}
+
+pub(crate) fn payload_hash(system_prompt: &str, user_prompt: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(system_prompt.as_bytes());
+    hasher.update([0u8]);
+    hasher.update(user_prompt.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Audit log location: `[audit] path`, or `audit.jsonl` next to config.toml
+pub(crate) fn audit_log_path(configured: Option<&str>) -> Result<PathBuf> {
+    if let Some(path) = configured {
+        return Ok(PathBuf::from(path));
+    }
+    let config_file = crate::config::config_file_path()?;
+    Ok(config_file.with_file_name("audit.jsonl"))
+}
+
+/// Append an entry when `[audit] enabled = true`
+pub(crate) fn record(entry: &AuditEntry) -> Result<()> {
+    let config = crate::config::load_config()?;
+    if !config.audit.enabled {
+        return Ok(());
+    }
+
+    let path = audit_log_path(config.audit.path.as_deref())?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open audit log {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)
+        .with_context(|| format!("Failed to write audit log {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_hash_separates_system_and_user_prompts() {
+        assert_eq!(payload_hash("a", "b"), payload_hash("a", "b"));
+        assert_ne!(payload_hash("ab", ""), payload_hash("a", "b"));
+        assert_eq!(payload_hash("", "").len(), 64);
+    }
+}
@@ -0,0 +1,109 @@
+use super::CompletionProvider;
+use super::audit::{payload_hash, AuditEntry};
+use anyhow::Result;
+use async_trait::async_trait;
+use console::style;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set by the global `--show-prompt` flag
+static SHOW_PROMPT: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn set_show_prompt(enabled: bool) {
+    SHOW_PROMPT.store(enabled, Ordering::Relaxed);
+}
+
+/// Wraps every provider: redacts secrets, optionally previews the prompt, and writes the audit log
+pub(crate) struct GuardedProvider {
+    pub(crate) provider_name: String,
+    pub(crate) inner: Box<dyn CompletionProvider + Send + Sync>,
+}
+
+#[async_trait]
+impl CompletionProvider for GuardedProvider {
+    async fn generate_content(
+        &self,
+        model: &str,
+        system_prompt: &str,
+        user_prompt: &str,
+    ) -> Result<String> {
+        let (system_prompt, user_prompt, redactions) = if crate::redact::is_enabled() {
+            let mut redactor = crate::redact::Redactor::new();
+            let system_prompt = redactor.redact(system_prompt);
+            let user_prompt = redactor.redact(user_prompt);
+            crate::flow::flows_spinner::suspend_spinner(|| {
+                crate::redact::print_redactions(redactor.redactions())
+            });
+            (system_prompt, user_prompt, redactor.redactions().len())
+        } else {
+            (system_prompt.to_string(), user_prompt.to_string(), 0)
+        };
+
+        if SHOW_PROMPT.load(Ordering::Relaxed) {
+            let send = crate::flow::flows_spinner::suspend_spinner(|| {
+                preview_and_confirm(&self.provider_name, model, &system_prompt, &user_prompt)
+            })?;
+            if !send {
+                anyhow::bail!("Prompt was not sent to the AI provider");
+            }
+        }
+
+        let result = self
+            .inner
+            .generate_content(model, &system_prompt, &user_prompt)
+            .await;
+
+        let entry = AuditEntry {
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            command: std::env::args().nth(1).unwrap_or_default(),
+            provider: self.provider_name.clone(),
+            model: model.to_string(),
+            system_bytes: system_prompt.len(),
+            user_bytes: user_prompt.len(),
+            response_bytes: result.as_ref().map(|r| r.len()).unwrap_or(0),
+            payload_sha256: payload_hash(&system_prompt, &user_prompt),
+            redactions,
+            ok: result.is_ok(),
+        };
+        if let Err(e) = super::audit::record(&entry) {
+            eprintln!(
+                "{} {}",
+                style("Warning:").yellow().bold(),
+                style(format!("Failed to write audit log: {}", e)).yellow()
+            );
+        }
+
+        result
+    }
+}
+
+/// Rough token count (~4 bytes per token) for the preview
+fn estimate_tokens(text: &str) -> usize {
+    text.len().div_ceil(4)
+}
+
+fn preview_and_confirm(provider: &str, model: &str, system_prompt: &str, user_prompt: &str) -> Result<bool> {
+    let rule = style("═".repeat(60)).dim();
+    eprintln!("\n{}", style("Prompt preview").bold().cyan());
+    eprintln!(
+        "{} {} / {}",
+        style("Provider:").bold(),
+        style(provider).cyan(),
+        style(model).cyan()
+    );
+
+    for (label, text) in [("SYSTEM PROMPT", system_prompt), ("USER PROMPT", user_prompt)] {
+        eprintln!(
+            "\n{} {}",
+            style(label).bold(),
+            style(format!("({} bytes, ~{} tokens)", text.len(), estimate_tokens(text))).dim()
+        );
+        eprintln!("{}", rule);
+        eprintln!("{}", text);
+        eprintln!("{}", rule);
+    }
+
+    crate::flow::flows_error::confirm_or_abort("Send this prompt to the AI provider?", false)
+}
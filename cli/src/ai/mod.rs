@@ -2,7 +2,10 @@ use anyhow::Result;
 use async_trait::async_trait;
 use crate::config::{get_api_key, get_orca_base_url, get_orca_token, get_provider};
 
+pub(crate) mod audit;
 pub(crate) mod gemini;
+pub(crate) mod guard;
+pub(crate) mod models;
 pub(crate) mod openai;
 pub(crate) mod orca;
 
@@ -17,6 +20,14 @@ pub(crate) trait CompletionProvider {
 }
 
 pub(crate) async fn create_provider() -> Result<Box<dyn CompletionProvider + Send + Sync>> {
+    let provider = create_raw_provider().await?;
+    Ok(Box::new(guard::GuardedProvider {
+        provider_name: get_provider(),
+        inner: provider,
+    }))
+}
+
+async fn create_raw_provider() -> Result<Box<dyn CompletionProvider + Send + Sync>> {
     let provider_name = get_provider();
     let provider_name = provider_name.as_str();
 
@@ -0,0 +1,80 @@
+use crate::config::OrcaConfig;
+
+/// AI task with its own `[models]` entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)] // PrDescription/Review/Conflict: config keys exist before their AI flows do
+pub(crate) enum ModelTask {
+    Commit,
+    ReleaseNotes,
+    PrDescription,
+    Review,
+    Conflict,
+}
+
+/// Model used when nothing is configured, picked so it exists on the active provider
+pub(crate) fn provider_default_model(provider: &str) -> &'static str {
+    match provider {
+        "openai" | "rest" => "gpt-4o-mini",
+        "deepseek" => "deepseek-chat",
+        "zai" => "glm-4.6",
+        // gemini, and orca (the Orca server routes Gemini model names)
+        _ => "gemini-2.5-flash",
+    }
+}
+
+/// Resolve the model for a task: `--model` flag, then `[models].<task>`,
+/// then `git.default_model`, then the provider default
+pub(crate) fn resolve_model(task: ModelTask, flag: Option<&str>) -> String {
+    let config = crate::config::load_config().unwrap_or_default();
+    resolve_model_with(&config, task, flag)
+}
+
+fn resolve_model_with(config: &OrcaConfig, task: ModelTask, flag: Option<&str>) -> String {
+    let per_task = match task {
+        ModelTask::Commit => &config.models.commit,
+        ModelTask::ReleaseNotes => &config.models.release_notes,
+        ModelTask::PrDescription => &config.models.pr_description,
+        ModelTask::Review => &config.models.review,
+        ModelTask::Conflict => &config.models.conflict,
+    };
+
+    [flag, per_task.as_deref(), config.git.default_model.as_deref()]
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .find(|m| !m.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| provider_default_model(&config.api.provider).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_from_flag_to_task_to_default_model_to_provider() {
+        let mut config = OrcaConfig::default();
+        config.api.provider = "openai".to_string();
+        assert_eq!(resolve_model_with(&config, ModelTask::ReleaseNotes, None), "gpt-4o-mini");
+
+        config.git.default_model = Some("gpt-4o".to_string());
+        assert_eq!(resolve_model_with(&config, ModelTask::ReleaseNotes, None), "gpt-4o");
+
+        config.models.release_notes = Some("o3-mini".to_string());
+        assert_eq!(resolve_model_with(&config, ModelTask::ReleaseNotes, None), "o3-mini");
+        assert_eq!(resolve_model_with(&config, ModelTask::Commit, None), "gpt-4o");
+
+        assert_eq!(
+            resolve_model_with(&config, ModelTask::ReleaseNotes, Some("gpt-4.1")),
+            "gpt-4.1"
+        );
+    }
+
+    #[test]
+    fn blank_entries_are_ignored() {
+        let mut config = OrcaConfig::default();
+        config.api.provider = "deepseek".to_string();
+        config.models.commit = Some("  ".to_string());
+        assert_eq!(resolve_model_with(&config, ModelTask::Commit, None), "deepseek-chat");
+    }
+}
@@ -0,0 +1,153 @@
+use crate::config::{ProtectedBranchRule, ProtectionAction, REPO_CONFIG_FILE};
+use anyhow::Result;
+use console::style;
+
+/// Things orca does to a branch that a `[[protected_branches]]` rule can warn about or block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ProtectedOperation {
+    Push,
+    ForcePush,
+    Commit,
+    Rewrite,
+}
+
+impl ProtectedOperation {
+    pub(crate) fn describe(self) -> &'static str {
+        match self {
+            ProtectedOperation::Push => "pushing",
+            ProtectedOperation::ForcePush => "force-pushing",
+            ProtectedOperation::Commit => "committing",
+            ProtectedOperation::Rewrite => "rewriting history",
+        }
+    }
+
+    fn action(self, rule: &ProtectedBranchRule) -> ProtectionAction {
+        match self {
+            ProtectedOperation::Push => rule.push,
+            ProtectedOperation::ForcePush => rule.force_push,
+            ProtectedOperation::Commit => rule.commit,
+            ProtectedOperation::Rewrite => rule.rewrite,
+        }
+    }
+}
+
+/// The strictest rule matching a branch for one operation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Verdict {
+    pub(crate) action: ProtectionAction,
+    pub(crate) pattern: String,
+}
+
+/// None when no rule matching `branch` restricts `operation`
+pub(crate) fn evaluate(rules: &[ProtectedBranchRule], branch: &str, operation: ProtectedOperation) -> Option<Verdict> {
+    rules
+        .iter()
+        .filter(|rule| glob_match(&rule.pattern, branch))
+        .map(|rule| Verdict {
+            action: operation.action(rule),
+            pattern: rule.pattern.clone(),
+        })
+        .filter(|verdict| verdict.action != ProtectionAction::Allow)
+        .max_by_key(|verdict| verdict.action)
+}
+
+/// Stop a blocked operation on `branch`, or print a warning and carry on
+pub(crate) fn enforce(branch: &str, operation: ProtectedOperation) -> Result<()> {
+    let rules = crate::config::load_config()?.protected_branches;
+    let Some(verdict) = evaluate(&rules, branch, operation) else {
+        return Ok(());
+    };
+
+    match verdict.action {
+        ProtectionAction::Block => anyhow::bail!(
+            "Protected branch '{}' (rule '{}'): {} is blocked. Work on a feature branch instead, \
+             or change the rule under [[protected_branches]] in {}",
+            branch,
+            verdict.pattern,
+            operation.describe(),
+            REPO_CONFIG_FILE
+        ),
+        ProtectionAction::Warn => eprintln!(
+            "{} {}",
+            style("[!]").yellow().bold(),
+            style(format!(
+                "Protected branch '{}' (rule '{}'): {} here is discouraged; consider a feature branch",
+                branch,
+                verdict.pattern,
+                operation.describe()
+            ))
+            .yellow()
+        ),
+        ProtectionAction::Allow => {}
+    }
+    Ok(())
+}
+
+/// `enforce` for the checked-out branch; a detached HEAD is never protected
+pub(crate) fn enforce_current(operation: ProtectedOperation) -> Result<()> {
+    let branch = crate::git::current_branch()?;
+    if branch.is_empty() {
+        return Ok(());
+    }
+    enforce(&branch, operation)
+}
+
+/// Branch glob: `*` and `?` stay within one `/` segment, `**` crosses them
+pub(crate) fn glob_match(pattern: &str, branch: &str) -> bool {
+    fn matches(pattern: &[u8], text: &[u8]) -> bool {
+        match pattern {
+            [] => text.is_empty(),
+            [b'*', b'*', rest @ ..] => (0..=text.len()).any(|i| matches(rest, &text[i..])),
+            [b'*', rest @ ..] => {
+                let segment = text.iter().position(|b| *b == b'/').unwrap_or(text.len());
+                (0..=segment).any(|i| matches(rest, &text[i..]))
+            }
+            [b'?', rest @ ..] => text.first().is_some_and(|b| *b != b'/') && matches(rest, &text[1..]),
+            [p, rest @ ..] => text.first() == Some(p) && matches(rest, &text[1..]),
+        }
+    }
+    matches(pattern.as_bytes(), branch.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, push: ProtectionAction) -> ProtectedBranchRule {
+        ProtectedBranchRule {
+            pattern: pattern.to_string(),
+            push,
+            force_push: ProtectionAction::Block,
+            commit: ProtectionAction::Allow,
+            rewrite: ProtectionAction::Block,
+        }
+    }
+
+    #[test]
+    fn globs_match_branch_segments() {
+        assert!(glob_match("main", "main"));
+        assert!(!glob_match("main", "maintenance"));
+        assert!(glob_match("release/*", "release/1.2"));
+        assert!(!glob_match("release/*", "release/1.2/hotfix"));
+        assert!(glob_match("release/**", "release/1.2/hotfix"));
+        assert!(glob_match("v?.x", "v2.x"));
+        assert!(!glob_match("*", "team/feature"));
+    }
+
+    #[test]
+    fn strictest_matching_rule_wins() {
+        let rules = vec![
+            rule("release/*", ProtectionAction::Warn),
+            rule("release/2.*", ProtectionAction::Block),
+        ];
+        let verdict = evaluate(&rules, "release/2.0", ProtectedOperation::Push).unwrap();
+        assert_eq!(verdict.action, ProtectionAction::Block);
+        assert_eq!(verdict.pattern, "release/2.*");
+        assert_eq!(
+            evaluate(&rules, "release/1.0", ProtectedOperation::Push).map(|v| v.action),
+            Some(ProtectionAction::Warn)
+        );
+        assert_eq!(evaluate(&rules, "release/2.0", ProtectedOperation::Commit), None);
+        assert_eq!(evaluate(&rules, "feature/x", ProtectedOperation::Rewrite), None);
+    }
+}
@@ -1,12 +1,8 @@
 use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
-fn default_model() -> String {
-    "gemini-2.5-flash".to_string()
-}
-
 fn default_base_branch() -> String {
-    "main".to_string()
+    crate::config::default_base_branch()
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
@@ -15,9 +11,22 @@ pub(crate) enum CommitStylePreset {
     ConventionalEmojis,
     Gitmoji,
     Jira,
+    Linear,
+    Github,
     Simple,
 }
 
+/// Output of `orca safe scan` / `orca safe preflight`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum ReportFormat {
+    /// Colored terminal output
+    Text,
+    /// Stable JSON schema (schema_version 1)
+    Json,
+    /// SARIF 2.1.0 for code-scanning dashboards
+    Sarif,
+}
+
 impl CommitStylePreset {
     pub(crate) fn instruction(self) -> &'static str {
         match self {
@@ -31,13 +40,30 @@ impl CommitStylePreset {
                 "Use gitmoji-style commit messages (e.g. ✨ Add feature, 🐛 Fix bug). No Conventional Commits prefixes unless it fits naturally. Subject <= 72 chars."
             }
             CommitStylePreset::Jira => {
-                "Prefix the subject with a Jira key if it can be inferred (e.g. PROJ-123). Format: PROJ-123 feat: <subject>. If no key is obvious, omit it. Subject <= 72 chars."
+                "Format: <type>: <subject> (e.g. feat: add login). Do not invent Jira keys; the key is added from the branch name. Subject <= 72 chars."
+            }
+            CommitStylePreset::Linear => {
+                "Format: <type>: <subject> (e.g. fix: handle empty cart). Do not invent Linear issue IDs; the ID is added from the branch name. Subject <= 72 chars."
+            }
+            CommitStylePreset::Github => {
+                "Conventional Commits (feat/fix/refactor/chore/docs/test). Do not invent issue numbers; the #number is added from the branch name. Subject <= 72 chars."
             }
             CommitStylePreset::Simple => {
                 "Simple imperative subject line only. No prefixes, no emojis. Subject <= 72 chars."
             }
         }
     }
+
+    /// Recover the preset from a style string: either a preset name ("jira") or its full instruction
+    pub(crate) fn from_style(style: &str) -> Option<Self> {
+        let style = style.trim();
+        Self::from_str(style, true).ok().or_else(|| {
+            Self::value_variants()
+                .iter()
+                .copied()
+                .find(|p| p.instruction() == style)
+        })
+    }
 }
 
 #[derive(Parser, Debug)]
@@ -53,6 +79,15 @@ pub(crate) struct Cli {
 
     #[arg(long, action = clap::ArgAction::SetTrue, global = true)]
     pub(crate) yes_pr: bool,
+
+    /// Send diffs and prompts to the AI provider without redacting detected secrets
+    #[arg(long, action = clap::ArgAction::SetTrue, global = true)]
+    pub(crate) no_redact: bool,
+
+    /// Print the exact prompt sent to the AI provider and ask before sending it
+    #[arg(long, action = clap::ArgAction::SetTrue, global = true)]
+    pub(crate) show_prompt: bool,
+
     #[command(subcommand)]
     pub(crate) command: Option<Commands>,
 }
@@ -79,9 +114,9 @@ pub(crate) enum Commands {
         #[arg(long, default_value_t = false)]
         dry_run: bool,
 
-        /// Model name for AI (used when generating new plan)
-        #[arg(long, default_value_t = default_model())]
-        model: String,
+        /// Model name for AI (default: [models].commit, git.default_model, or the provider's default)
+        #[arg(long)]
+        model: Option<String>,
 
         /// Style instruction for commit messages (e.g. "conventional commits with emojis")
         #[arg(long)]
@@ -130,6 +165,18 @@ pub(crate) enum Commands {
         /// Regenerate plan even if cache exists
         #[arg(long, default_value_t = false)]
         regenerate: bool,
+
+        /// Append a DCO Signed-off-by trailer from your git identity
+        #[arg(long, default_value_t = false, conflicts_with = "plan_only")]
+        signoff: bool,
+
+        /// Add a "Refs: <ISSUE>" trailer (repeatable; e.g. 123 or PROJ-123)
+        #[arg(long = "refs", value_name = "ISSUE", conflicts_with = "plan_only")]
+        refs: Vec<String>,
+
+        /// Add a "Closes: <ISSUE>" trailer (repeatable)
+        #[arg(long = "closes", value_name = "ISSUE", conflicts_with = "plan_only")]
+        closes: Vec<String>,
     },
 
     #[command(next_help_heading = "Core Commands")]
@@ -200,6 +247,24 @@ pub(crate) enum Commands {
     #[command(alias = "td", subcommand)]
     Tidy(TidyCommands),
 
+    #[command(next_help_heading = "Workflow Commands")]
+    /// Manage the pairing list used for Co-authored-by trailers
+    #[command(subcommand)]
+    Pair(PairCommands),
+
+    #[command(next_help_heading = "Workflow Commands")]
+    /// Show the AI description stored for a commit
+    Explain {
+        /// Commit to explain
+        #[arg(default_value = "HEAD")]
+        commit: String,
+    },
+
+    #[command(next_help_heading = "Workflow Commands")]
+    /// Share commit descriptions stored in refs/notes/orca
+    #[command(subcommand)]
+    Notes(NotesCommands),
+
     // ============ ADVANCED COMMANDS ============
     #[command(next_help_heading = "Advanced Commands")]
     /// Git wrapper with enhanced output
@@ -226,6 +291,10 @@ pub(crate) enum Commands {
     #[command(subcommand)]
     Safe(SafeCommands),
 
+    /// Install git hooks that run orca's checks on every commit and push
+    #[command(subcommand)]
+    Hooks(HooksCommands),
+
     // ============ UTILITY COMMANDS ============
     #[command(next_help_heading = "Utility Commands")]
     /// Login via browser to obtain CLI token
@@ -243,6 +312,16 @@ pub(crate) enum Commands {
     /// Check for updates and auto-upgrade
     Update,
 
+    #[command(next_help_heading = "Utility Commands")]
+    /// Manage cached commit plans for this repository
+    #[command(subcommand)]
+    Cache(CacheCommands),
+
+    #[command(next_help_heading = "Utility Commands")]
+    /// Inspect and customize the AI prompt templates
+    #[command(subcommand)]
+    Prompts(PromptsCommands),
+
     // ============ BACKWARD COMPATIBILITY (HIDDEN) ============
     #[command(hide = true)]
     /// (Deprecated: use 'publish' instead) Publish current commits
@@ -264,8 +343,8 @@ pub(crate) enum Commands {
     #[command(hide = true)]
     /// (Deprecated: use 'commit --plan-only' instead) Generate a commit plan
     Plan {
-        #[arg(long, default_value_t = default_model())]
-        model: String,
+        #[arg(long)]
+        model: Option<String>,
         #[arg(long, default_value_t = false)]
         json_only: bool,
         #[arg(long)]
@@ -350,7 +429,7 @@ pub(crate) enum BranchCommands {
 
     /// Create new branch with naming convention
     New {
-        /// Branch type (feat, fix, chore, hotfix, release)
+        /// Branch type (feat, fix, chore, hotfix, release, or `git.branch_types`)
         #[arg(value_name = "TYPE")]
         branch_type: String,
 
@@ -430,6 +509,116 @@ pub(crate) enum TidyCommands {
     },
 }
 
+#[derive(Subcommand, Debug)]
+pub(crate) enum PairCommands {
+    /// Add a co-author to the pairing list
+    Add {
+        /// Co-author name
+        name: String,
+
+        /// Co-author email
+        email: String,
+    },
+
+    /// Remove a co-author by name or email
+    Remove {
+        /// Name or email of the co-author to remove
+        who: String,
+    },
+
+    /// Show the current pairing list
+    List,
+
+    /// Clear the pairing list
+    Clear,
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum NotesCommands {
+    /// Push commit descriptions to a remote
+    Push {
+        /// Remote to push to
+        #[arg(long, default_value = "origin")]
+        remote: String,
+    },
+
+    /// Fetch commit descriptions from a remote (local notes win on conflict)
+    Fetch {
+        /// Remote to fetch from
+        #[arg(long, default_value = "origin")]
+        remote: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum CacheCommands {
+    /// List cached plans, newest first
+    List,
+
+    /// Show a cached plan
+    Show {
+        /// Cache key (a unique prefix is enough)
+        key: String,
+    },
+
+    /// Remove all cached plans
+    Clear,
+
+    /// Remove expired plans and those beyond the configured limit
+    Prune,
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum PromptsCommands {
+    /// Show the templates in use and where each comes from
+    Show {
+        /// Only this prompt (commit, release-notes, release-create)
+        name: Option<String>,
+    },
+
+    /// Write the built-in templates to .orca/prompts/ for editing
+    Init {
+        /// Write to the global config directory instead of the repository
+        #[arg(long)]
+        global: bool,
+
+        /// Overwrite existing template files
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum HooksCommands {
+    /// Install managed hooks; existing hooks are kept and run first
+    Install {
+        /// Hook to install (repeatable; default: pre-commit, commit-msg, pre-push)
+        #[arg(long = "hook", value_enum)]
+        hooks: Vec<crate::hooks::HookKind>,
+
+        /// Also install prepare-commit-msg, which AI-drafts the message for a plain `git commit`
+        #[arg(long)]
+        ai_draft: bool,
+    },
+
+    /// Remove managed hooks and restore the hooks they chained to
+    Uninstall,
+
+    /// Show which hooks are installed
+    Status,
+
+    /// Run a hook's checks (called by the installed hook scripts)
+    #[command(hide = true)]
+    Run {
+        #[arg(value_enum)]
+        hook: crate::hooks::HookKind,
+
+        /// Arguments git passed to the hook
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 pub(crate) enum ConflictCommands {
     /// Show conflicted files and current state
@@ -523,22 +712,62 @@ pub(crate) enum StackCommands {
 
 #[derive(Subcommand, Debug)]
 pub(crate) enum SafeCommands {
-    /// Scan staged files for secrets and sensitive data
+    /// Scan staged changes for secrets and sensitive data
     Scan {
-        /// Scan all files instead of just staged
+        /// Scan all tracked files instead of just staged changes
         #[arg(long, default_value_t = false)]
         all: bool,
+
+        /// Scan the lines added by each commit in a range (e.g. origin/main..HEAD)
+        #[arg(long, value_name = "RANGE", conflicts_with = "all")]
+        range: Option<String>,
+
+        /// Scan every commit reachable from any branch or tag, including deleted files
+        #[arg(long, conflicts_with_all = ["all", "range"])]
+        history: bool,
+
+        /// With --history, only scan commits after this revision
+        #[arg(long, value_name = "REV", requires = "history")]
+        since: Option<String>,
+
+        /// Lowest confidence that fails the scan; weaker matches are only listed
+        #[arg(long, value_enum, default_value = "medium")]
+        min_confidence: crate::secret_rules::Confidence,
+
+        /// Record current findings in .orca-secrets-baseline.json as accepted
+        #[arg(long, conflicts_with = "format")]
+        update_baseline: bool,
+
+        /// Look for blobs over the size limit and LFS-tracked files committed
+        /// without LFS instead of secrets
+        #[arg(long, conflicts_with = "update_baseline")]
+        large_files: bool,
+
+        /// With --large-files, the size limit in KiB (default: [large_files] max_file_size_kb, else 5120)
+        #[arg(long, value_name = "KIB", requires = "large_files")]
+        max_size: Option<u64>,
+
+        /// Output format; json and sarif print only the report to stdout.
+        /// Exit status: 0 clean, 1 findings, 2 scan error
+        #[arg(long, value_enum, default_value = "text")]
+        format: ReportFormat,
     },
 
     /// Run preflight checks before push/tag/release
     Preflight {
-        /// Operation being performed
+        /// Operation being performed; runs the `[preflight.<operation>]` profile from
+        /// .orca.toml or the user config when one exists
         #[arg(long, default_value = "push")]
         operation: String,
 
-        /// Protected branch to check against
+        /// Protected branch to warn about instead of the [[protected_branches]] rules
         #[arg(long)]
         protection: Option<String>,
+
+        /// Output format; json and sarif print only the report to stdout.
+        /// Exit status: 0 passed, 1 failed checks, 2 preflight error
+        #[arg(long, value_enum, default_value = "text")]
+        format: ReportFormat,
     },
 }
 
@@ -559,7 +788,7 @@ mod tests {
         let cli = Cli::try_parse_from(["orca", "plan", "--out", "plan.json"]).expect("should parse");
         match cli.command.expect("expected subcommand") {
             Commands::Plan { model, json_only, out, .. } => {
-                assert_eq!(model, "gemini-2.5-flash");
+                assert_eq!(model, None);
                 assert!(!json_only);
                 assert_eq!(out.unwrap().to_string_lossy(), "plan.json");
             }
@@ -597,4 +826,42 @@ mod tests {
             _ => panic!("expected Apply"),
         }
     }
+
+    #[test]
+    fn parses_commit_trailer_flags() {
+        let cli = Cli::try_parse_from([
+            "orca",
+            "commit",
+            "--signoff",
+            "--refs",
+            "12",
+            "--closes",
+            "PROJ-7",
+            "--closes",
+            "8",
+        ])
+        .expect("should parse");
+
+        match cli.command.expect("expected subcommand") {
+            Commands::Commit { signoff, refs, closes, .. } => {
+                assert!(signoff);
+                assert_eq!(refs, vec!["12"]);
+                assert_eq!(closes, vec!["PROJ-7", "8"]);
+            }
+            _ => panic!("expected Commit"),
+        }
+    }
+
+    #[test]
+    fn parses_pair_add() {
+        let cli = Cli::try_parse_from(["orca", "pair", "add", "Jane Doe", "jane@example.com"])
+            .expect("should parse");
+        match cli.command.expect("expected subcommand") {
+            Commands::Pair(super::PairCommands::Add { name, email }) => {
+                assert_eq!(name, "Jane Doe");
+                assert_eq!(email, "jane@example.com");
+            }
+            _ => panic!("expected Pair Add"),
+        }
+    }
 }
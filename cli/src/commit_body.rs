@@ -0,0 +1,211 @@
+use crate::config::CommitConfig;
+use crate::plan::CommitDescription;
+
+/// Default layout; each placeholder expands to whole lines and empty sections are dropped
+pub(crate) const DEFAULT_BODY_TEMPLATE: &str =
+    "{summary}\n\nChanges:\n{changes}\n\n{impact}\n\n{breaking_changes}";
+
+pub(crate) const DEFAULT_WRAP_WIDTH: usize = 72;
+
+const PLACEHOLDERS: &[&str] = &["{summary}", "{changes}", "{impact}", "{breaking_changes}"];
+
+/// Render the commit body for a description, or `None` when descriptions are kept out of commits
+pub(crate) fn render_commit_body(config: &CommitConfig, desc: &CommitDescription) -> Option<String> {
+    if config.omit_description {
+        return None;
+    }
+
+    let template = config
+        .body_template
+        .as_deref()
+        .unwrap_or(DEFAULT_BODY_TEMPLATE);
+    let width = config.wrap_width.unwrap_or(DEFAULT_WRAP_WIDTH);
+
+    let body = wrap_body(&fill_template(template, desc), width);
+    if body.is_empty() { None } else { Some(body) }
+}
+
+fn placeholder_value(name: &str, desc: &CommitDescription) -> String {
+    match name {
+        "{summary}" => desc.summary.trim().to_string(),
+        "{changes}" => desc
+            .changes
+            .iter()
+            .map(|c| format!("- {}", c.trim()))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "{impact}" => match &desc.impact {
+            // Not written as `Impact: ...` so git doesn't mistake the paragraph for a trailer block
+            Some(impact) => {
+                let mut lines = vec![format!(
+                    "Impact ({}): {}",
+                    impact.level.to_uppercase(),
+                    impact.explanation.trim()
+                )];
+                if !impact.affected_areas.is_empty() {
+                    lines.push(format!("Affected areas: {}", impact.affected_areas.join(", ")));
+                }
+                lines.join("\n")
+            }
+            None => String::new(),
+        },
+        // One singular footer per entry, as the Conventional Commits spec expects
+        "{breaking_changes}" => desc
+            .breaking_changes
+            .iter()
+            .map(|bc| format!("BREAKING CHANGE: {}", bc.trim()))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => String::new(),
+    }
+}
+
+/// Substitute placeholders paragraph by paragraph, dropping paragraphs whose placeholders are all empty
+fn fill_template(template: &str, desc: &CommitDescription) -> String {
+    let mut paragraphs = Vec::new();
+
+    for paragraph in template.split("\n\n") {
+        let used: Vec<&str> = PLACEHOLDERS
+            .iter()
+            .copied()
+            .filter(|p| paragraph.contains(p))
+            .collect();
+
+        let mut filled = paragraph.to_string();
+        let mut any_value = used.is_empty();
+        for p in used {
+            let value = placeholder_value(p, desc);
+            any_value |= !value.is_empty();
+            filled = filled.replace(p, &value);
+        }
+
+        let filled = filled.trim_matches('\n');
+        if any_value && !filled.trim().is_empty() {
+            paragraphs.push(filled.to_string());
+        }
+    }
+
+    paragraphs.join("\n\n")
+}
+
+/// Wrap each line at `width` columns; 0 disables wrapping
+fn wrap_body(body: &str, width: usize) -> String {
+    if width == 0 {
+        return body.to_string();
+    }
+
+    body.lines()
+        .map(|line| wrap_line(line, width))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn wrap_line(line: &str, width: usize) -> String {
+    if line.chars().count() <= width {
+        return line.to_string();
+    }
+
+    // Bullets and footers continue with an indent so they stay a single item
+    let indent = if line.starts_with("- ") || is_footer(line) {
+        "  "
+    } else {
+        ""
+    };
+
+    let mut out: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in line.split_whitespace() {
+        let prefix = if out.is_empty() { "" } else { indent };
+        if current.is_empty() {
+            current = format!("{prefix}{word}");
+        } else if current.chars().count() + 1 + word.chars().count() <= width {
+            current.push(' ');
+            current.push_str(word);
+        } else {
+            out.push(std::mem::take(&mut current));
+            current = format!("{indent}{word}");
+        }
+    }
+    if !current.is_empty() {
+        out.push(current);
+    }
+
+    out.join("\n")
+}
+
+fn is_footer(line: &str) -> bool {
+    if line.starts_with("BREAKING CHANGE: ") {
+        return true;
+    }
+    line.split_once(": ").is_some_and(|(token, _)| {
+        !token.is_empty() && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::ImpactAnalysis;
+
+    fn description() -> CommitDescription {
+        CommitDescription {
+            summary: "Rework the login flow so sessions survive a token refresh \
+                      without forcing the user to sign in again"
+                .to_string(),
+            changes: vec!["Refresh tokens in the background".to_string()],
+            impact: Some(ImpactAnalysis {
+                level: "high".to_string(),
+                explanation: "All clients".to_string(),
+                affected_areas: vec!["auth".to_string()],
+            }),
+            breaking_changes: vec!["`/login` now returns 201".to_string()],
+        }
+    }
+
+    #[test]
+    fn default_template_wraps_and_uses_singular_footer() {
+        let body = render_commit_body(&CommitConfig::default(), &description()).unwrap();
+
+        assert!(body.lines().all(|l| l.chars().count() <= DEFAULT_WRAP_WIDTH));
+        assert!(body.contains("Changes:\n- Refresh tokens in the background"));
+        assert!(body.contains("Impact (HIGH): All clients\nAffected areas: auth"));
+        assert!(body.ends_with("BREAKING CHANGE: `/login` now returns 201"));
+        assert!(!body.contains("BREAKING CHANGES"));
+    }
+
+    #[test]
+    fn empty_sections_are_dropped() {
+        let desc = CommitDescription {
+            summary: "Short summary".to_string(),
+            changes: vec![],
+            impact: None,
+            breaking_changes: vec![],
+        };
+        let body = render_commit_body(&CommitConfig::default(), &desc).unwrap();
+        assert_eq!(body, "Short summary");
+    }
+
+    #[test]
+    fn custom_template_and_omit_option() {
+        let config = CommitConfig {
+            body_template: Some("Why: {summary}\n\n{breaking_changes}".to_string()),
+            wrap_width: Some(0),
+            ..Default::default()
+        };
+        let body = render_commit_body(&config, &description()).unwrap();
+        assert!(body.starts_with("Why: Rework the login flow"));
+        assert_eq!(body.lines().count(), 3);
+
+        let omitted = CommitConfig {
+            omit_description: true,
+            ..Default::default()
+        };
+        assert_eq!(render_commit_body(&omitted, &description()), None);
+    }
+
+    #[test]
+    fn wrapped_bullets_keep_hanging_indent() {
+        let wrapped = wrap_line("- one two three four five", 12);
+        assert_eq!(wrapped, "- one two\n  three four\n  five");
+    }
+}
@@ -1,130 +1,295 @@
 use crate::plan::CommitPlan;
 use anyhow::{Context, Result};
-use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+
+const DEFAULT_MAX_ENTRIES: usize = 20;
+const DEFAULT_TTL_HOURS: u64 = 24;
+
+/// Mọi input ảnh hưởng tới plan; đổi bất kỳ field nào sẽ ra cache key khác
+pub(crate) struct PlanCacheKey<'a> {
+    pub(crate) diff: &'a str,
+    pub(crate) status: &'a str,
+    pub(crate) model: &'a str,
+    pub(crate) style: Option<&'a str>,
+    pub(crate) language: Option<&'a str>,
+    /// `PromptTemplates::cache_version` of the commit prompt
+    pub(crate) prompt_version: &'a str,
+}
+
+impl PlanCacheKey<'_> {
+    /// SHA-256 over length-prefixed fields (stable across Rust versions, unlike `DefaultHasher`)
+    pub(crate) fn digest(&self) -> String {
+        let mut hasher = Sha256::new();
+        let fields = [
+            Some(self.diff),
+            Some(self.status),
+            Some(self.model),
+            self.style,
+            self.language,
+            Some(self.prompt_version),
+        ];
+        for field in fields {
+            match field {
+                Some(value) => {
+                    hasher.update([1u8]);
+                    hasher.update((value.len() as u64).to_le_bytes());
+                    hasher.update(value.as_bytes());
+                }
+                None => hasher.update([0u8]),
+            }
+        }
+        format!("{:x}", hasher.finalize())
+    }
+}
 
+/// Một plan đã cache, lưu tại `<git-dir>/orca_cache/plans/<key>.json`
 #[derive(Debug, Serialize, Deserialize)]
-struct CachedPlan {
+pub(crate) struct CacheEntry {
+    pub(crate) key: String,
     /// Timestamp khi cache được tạo
+    pub(crate) created_at: u64,
+    pub(crate) model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) style: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) language: Option<String>,
+    pub(crate) plan: CommitPlan,
+}
+
+/// Plan được apply gần nhất (dùng khi tạo PR cho commit chưa có git note)
+#[derive(Debug, Serialize, Deserialize)]
+struct LatestPlan {
     timestamp: u64,
-    /// Hash của diff để detect changes
-    diff_hash: String,
-    /// The cached plan
     plan: CommitPlan,
 }
 
 /// Lấy cache directory
 fn get_cache_dir() -> Result<PathBuf> {
-    let repo_root = crate::git::get_repo_root()?;
-    let cache_dir = repo_root.join(".git").join("orca_cache");
+    // --absolute-git-dir also works inside worktrees, where `.git` is a file
+    let git_dir = crate::git::run_git(&["rev-parse", "--absolute-git-dir"])?;
+    let cache_dir = PathBuf::from(git_dir.trim()).join("orca_cache");
     std::fs::create_dir_all(&cache_dir)?;
     Ok(cache_dir)
 }
 
-/// Cache file path
-fn get_cache_file() -> Result<PathBuf> {
-    Ok(get_cache_dir()?.join("commit_plan.json"))
+fn get_plans_dir() -> Result<PathBuf> {
+    let dir = get_cache_dir()?.join("plans");
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn get_latest_file() -> Result<PathBuf> {
+    Ok(get_cache_dir()?.join("latest_plan.json"))
+}
+
+fn now_secs() -> Result<u64> {
+    Ok(std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs())
 }
 
-/// Tính hash của diff
-fn hash_diff(diff: &str) -> String {
-    use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
-    let mut hasher = DefaultHasher::new();
-    diff.hash(&mut hasher);
-    format!("{:x}", hasher.finish())
+/// (max entries, TTL in seconds) from `[cache]` config
+fn cache_limits() -> (usize, u64) {
+    let config = crate::config::load_config().unwrap_or_default();
+    let max_entries = config.cache.max_entries.unwrap_or(DEFAULT_MAX_ENTRIES);
+    let ttl_hours = config.cache.ttl_hours.unwrap_or(DEFAULT_TTL_HOURS);
+    (max_entries, ttl_hours.saturating_mul(3600))
 }
 
 /// Lưu plan vào cache
-pub fn cache_plan(plan: &CommitPlan, diff: &str) -> Result<()> {
-    let cache_file = get_cache_file()?;
-    let cached = CachedPlan {
-        timestamp: std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)?
-            .as_secs(),
-        diff_hash: hash_diff(diff),
+pub fn cache_plan(key: &PlanCacheKey, plan: &CommitPlan) -> Result<()> {
+    let digest = key.digest();
+    let entry = CacheEntry {
+        key: digest.clone(),
+        created_at: now_secs()?,
+        model: key.model.to_string(),
+        style: key.style.map(str::to_string),
+        language: key.language.map(str::to_string),
         plan: plan.clone(),
     };
-    
-    let json = serde_json::to_string_pretty(&cached)?;
+
+    let cache_file = get_plans_dir()?.join(format!("{}.json", digest));
+    let json = serde_json::to_string_pretty(&entry)?;
     std::fs::write(&cache_file, json)
         .with_context(|| format!("Failed to write cache to {}", cache_file.display()))?;
-    
+
+    prune_cache()?;
     Ok(())
 }
 
+/// Load plan từ cache nếu hợp lệ
+pub fn load_cached_plan(key: &PlanCacheKey) -> Result<Option<CommitPlan>> {
+    let cache_file = get_plans_dir()?.join(format!("{}.json", key.digest()));
+    if !cache_file.exists() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(&cache_file)?;
+    let entry: CacheEntry = serde_json::from_str(&content)?;
+
+    let (_, ttl) = cache_limits();
+    if now_secs()?.saturating_sub(entry.created_at) > ttl {
+        return Ok(None);
+    }
+
+    Ok(Some(entry.plan))
+}
+
 pub fn cache_latest_plan(plan: &CommitPlan) -> Result<()> {
-    let cache_file = get_cache_file()?;
-    let cached = CachedPlan {
-        timestamp: std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)?
-            .as_secs(),
-        diff_hash: String::new(),
+    let cache_file = get_latest_file()?;
+    let latest = LatestPlan {
+        timestamp: now_secs()?,
         plan: plan.clone(),
     };
 
-    let json = serde_json::to_string_pretty(&cached)?;
+    let json = serde_json::to_string_pretty(&latest)?;
     std::fs::write(&cache_file, json)
         .with_context(|| format!("Failed to write cache to {}", cache_file.display()))?;
 
     Ok(())
 }
 
-/// Load plan từ cache nếu hợp lệ
-pub fn load_cached_plan(diff: &str) -> Result<Option<CommitPlan>> {
-    let cache_file = get_cache_file()?;
-    
+pub fn load_latest_cached_plan() -> Result<Option<CommitPlan>> {
+    let cache_file = get_latest_file()?;
     if !cache_file.exists() {
         return Ok(None);
     }
-    
+
     let content = std::fs::read_to_string(&cache_file)?;
-    let cached: CachedPlan = serde_json::from_str(&content)?;
-    
-    // Check if diff has changed
-    if cached.diff_hash != hash_diff(diff) {
+    let latest: LatestPlan = serde_json::from_str(&content)?;
+
+    let (_, ttl) = cache_limits();
+    if now_secs()?.saturating_sub(latest.timestamp) > ttl {
         return Ok(None);
     }
-    
-    // Check if cache is not too old (e.g., 1 hour)
-    let now = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)?
-        .as_secs();
-    let age = now - cached.timestamp;
-    if age > 3600 {
-        return Ok(None);
+
+    Ok(Some(latest.plan))
+}
+
+/// All cached plans, newest first; unreadable files are skipped
+pub(crate) fn list_entries() -> Result<Vec<CacheEntry>> {
+    let mut entries = Vec::new();
+    for dir_entry in std::fs::read_dir(get_plans_dir()?)? {
+        let path = dir_entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        if let Ok(entry) = serde_json::from_str::<CacheEntry>(&content) {
+            entries.push(entry);
+        }
     }
-    
-    Ok(Some(cached.plan))
+    entries.sort_by_key(|e| std::cmp::Reverse(e.created_at));
+    Ok(entries)
 }
 
-pub fn load_latest_cached_plan() -> Result<Option<CommitPlan>> {
-    let cache_file = get_cache_file()?;
+/// Find a cached plan by (a unique prefix of) its key
+pub(crate) fn find_entry(key_prefix: &str) -> Result<CacheEntry> {
+    let mut matches: Vec<CacheEntry> = list_entries()?
+        .into_iter()
+        .filter(|e| e.key.starts_with(key_prefix))
+        .collect();
 
-    if !cache_file.exists() {
-        return Ok(None);
+    match matches.len() {
+        0 => anyhow::bail!("No cached plan matches '{}'", key_prefix),
+        1 => Ok(matches.remove(0)),
+        n => anyhow::bail!("'{}' matches {} cached plans; use a longer key", key_prefix, n),
     }
+}
 
-    let content = std::fs::read_to_string(&cache_file)?;
-    let cached: CachedPlan = serde_json::from_str(&content)?;
+/// Whether a cached plan has outlived the configured TTL
+pub(crate) fn is_expired(entry: &CacheEntry) -> bool {
+    let (_, ttl) = cache_limits();
+    now_secs()
+        .map(|now| now.saturating_sub(entry.created_at) > ttl)
+        .unwrap_or(false)
+}
 
-    let now = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)?
-        .as_secs();
-    let age = now - cached.timestamp;
-    if age > 3600 {
-        return Ok(None);
+/// Remove expired plans and anything beyond the newest `max_entries`; returns how many were removed
+pub fn prune_cache() -> Result<usize> {
+    let (max_entries, ttl) = cache_limits();
+    let entries: Vec<(String, u64)> = list_entries()?
+        .into_iter()
+        .map(|e| (e.key, e.created_at))
+        .collect();
+
+    let plans_dir = get_plans_dir()?;
+    let stale = keys_to_prune(&entries, now_secs()?, max_entries, ttl);
+    for key in &stale {
+        let _ = std::fs::remove_file(plans_dir.join(format!("{}.json", key)));
     }
+    Ok(stale.len())
+}
 
-    Ok(Some(cached.plan))
+/// Clear cache; returns how many plans were removed
+pub fn clear_cache() -> Result<usize> {
+    let cache_dir = get_cache_dir()?;
+    let removed = list_entries()?.len();
+
+    let plans_dir = cache_dir.join("plans");
+    if plans_dir.exists() {
+        std::fs::remove_dir_all(&plans_dir)?;
+    }
+    // Also drop the latest plan and the pre-multi-entry cache file
+    for name in ["latest_plan.json", "commit_plan.json"] {
+        let file = cache_dir.join(name);
+        if file.exists() {
+            std::fs::remove_file(&file)?;
+        }
+    }
+    Ok(removed)
 }
 
-/// Clear cache
-#[allow(dead_code)]
-pub fn clear_cache() -> Result<()> {
-    let cache_file = get_cache_file()?;
-    if cache_file.exists() {
-        std::fs::remove_file(&cache_file)?;
+/// `entries` must be sorted newest first
+fn keys_to_prune(entries: &[(String, u64)], now: u64, max_entries: usize, ttl: u64) -> Vec<String> {
+    entries
+        .iter()
+        .enumerate()
+        .filter(|(idx, (_, created_at))| *idx >= max_entries || now.saturating_sub(*created_at) > ttl)
+        .map(|(_, (key, _))| key.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key<'a>(model: &'a str, style: Option<&'a str>) -> PlanCacheKey<'a> {
+        PlanCacheKey {
+            diff: "diff --git a/x b/x",
+            status: " M x",
+            model,
+            style,
+            language: None,
+            prompt_version: "1/1+000000000000",
+        }
+    }
+
+    #[test]
+    fn key_depends_on_model_and_style() {
+        let base = key("gemini-2.5-flash", None).digest();
+        assert_eq!(base, key("gemini-2.5-flash", None).digest());
+        assert_eq!(base.len(), 64);
+        assert_ne!(base, key("gpt-4o", None).digest());
+        assert_ne!(base, key("gemini-2.5-flash", Some("gitmoji")).digest());
+        // An empty style is not the same as no style
+        assert_ne!(base, key("gemini-2.5-flash", Some("")).digest());
+    }
+
+    #[test]
+    fn prunes_expired_and_excess_entries() {
+        let entries = vec![
+            ("newest".to_string(), 1_000),
+            ("recent".to_string(), 990),
+            ("old".to_string(), 900),
+            ("oldest".to_string(), 100),
+        ];
+        assert_eq!(keys_to_prune(&entries, 1_000, 10, 500), vec!["oldest"]);
+        assert_eq!(keys_to_prune(&entries, 1_000, 2, 500), vec!["old", "oldest"]);
+        assert!(keys_to_prune(&entries, 1_000, 10, 10_000).is_empty());
     }
-    Ok(())
 }
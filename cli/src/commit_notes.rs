@@ -0,0 +1,72 @@
+use crate::git::{run_git, run_git_with_input};
+use crate::plan::CommitDescription;
+use anyhow::{Context, Result};
+
+/// Notes ref holding one JSON `CommitDescription` per commit
+pub(crate) const NOTES_REF: &str = "refs/notes/orca";
+
+/// Scratch ref that fetched notes land in before being merged into `NOTES_REF`
+const REMOTE_NOTES_REF: &str = "refs/notes/orca-remote";
+
+/// Attach a commit description to a commit as a git note (replacing any existing one)
+pub(crate) fn write_note(hash: &str, desc: &CommitDescription) -> Result<()> {
+    let json = serde_json::to_string_pretty(desc)?;
+    run_git_with_input(
+        &["notes", "--ref", NOTES_REF, "add", "-f", "-F", "-", hash],
+        &json,
+    )
+    .with_context(|| format!("Failed to write orca note for {}", hash))?;
+
+    ensure_notes_follow_rewrites();
+    Ok(())
+}
+
+/// Read the commit description stored for a commit, if any
+pub(crate) fn read_note(hash: &str) -> Option<CommitDescription> {
+    let raw = run_git(&["notes", "--ref", NOTES_REF, "show", hash]).ok()?;
+    serde_json::from_str(raw.trim()).ok()
+}
+
+/// Push the notes ref so teammates get the descriptions
+pub(crate) fn push_notes(remote: &str) -> Result<()> {
+    run_git(&["push", remote, NOTES_REF])
+        .with_context(|| format!("Failed to push {} to {}", NOTES_REF, remote))?;
+    Ok(())
+}
+
+/// Fetch the remote notes ref and merge it into ours; local notes win on conflict
+pub(crate) fn fetch_notes(remote: &str) -> Result<()> {
+    let refspec = format!("+{}:{}", NOTES_REF, REMOTE_NOTES_REF);
+    run_git(&["fetch", remote, &refspec])
+        .with_context(|| format!("Failed to fetch {} from {}", NOTES_REF, remote))?;
+    run_git(&["notes", "--ref", NOTES_REF, "merge", "-s", "ours", REMOTE_NOTES_REF])
+        .context("Failed to merge fetched orca notes")?;
+    Ok(())
+}
+
+/// Push notes after a publish when `commit.sync_notes` is enabled; failures only warn
+pub(crate) fn sync_after_push(remote: &str) {
+    let enabled = crate::config::load_config()
+        .map(|c| c.commit.sync_notes)
+        .unwrap_or(false);
+    if !enabled {
+        return;
+    }
+
+    if let Err(e) = push_notes(remote) {
+        eprintln!(
+            "{} {}",
+            console::style("Warning:").yellow().bold(),
+            console::style(format!("Could not push commit notes: {}", e)).yellow()
+        );
+    }
+}
+
+/// Let git carry our notes across `commit --amend` and `rebase`
+fn ensure_notes_follow_rewrites() {
+    let configured = run_git(&["config", "--get-all", "notes.rewriteRef"]).unwrap_or_default();
+    if configured.lines().any(|l| l.trim() == NOTES_REF) {
+        return;
+    }
+    let _ = run_git(&["config", "--add", "notes.rewriteRef", NOTES_REF]);
+}
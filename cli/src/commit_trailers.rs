@@ -0,0 +1,165 @@
+use crate::config::{CoAuthor, CommitConfig};
+use crate::git::{config_get, current_branch, run_git_with_input};
+use anyhow::Result;
+use console::style;
+
+/// Trailer options passed on the command line
+#[derive(Debug, Default, Clone)]
+pub(crate) struct TrailerOptions {
+    pub(crate) signoff: bool,
+    pub(crate) refs: Vec<String>,
+    pub(crate) closes: Vec<String>,
+}
+
+/// A single `Key: value` commit trailer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Trailer {
+    pub(crate) key: String,
+    pub(crate) value: String,
+}
+
+impl Trailer {
+    fn new(key: &str, value: impl Into<String>) -> Self {
+        Self {
+            key: key.to_string(),
+            value: value.into(),
+        }
+    }
+
+    fn as_arg(&self) -> String {
+        format!("{}: {}", self.key, self.value)
+    }
+}
+
+/// Resolve the trailers to append from config, command-line flags and the current branch
+pub(crate) fn resolve_trailers(opts: &TrailerOptions) -> Result<Vec<Trailer>> {
+    let config = crate::config::load_config()?;
+    let branch = current_branch().unwrap_or_default();
+
+    let mut trailers = Vec::new();
+    if opts.signoff || config.commit.signoff {
+        trailers.push(signoff_trailer()?);
+    }
+    trailers.extend(config.commit.co_authors.iter().map(co_author_trailer));
+    let branch_key = crate::issue_key::infer_from_branch(&branch, &config.issues.patterns);
+    trailers.extend(issue_trailers(&config.commit, opts, branch_key));
+    Ok(trailers)
+}
+
+/// Append trailers to a commit message using `git interpret-trailers`
+pub(crate) fn apply_trailers(message: &str, trailers: &[Trailer]) -> Result<String> {
+    if trailers.is_empty() {
+        return Ok(message.to_string());
+    }
+
+    let trailer_args: Vec<String> = trailers.iter().map(Trailer::as_arg).collect();
+    let mut args: Vec<&str> = vec!["interpret-trailers", "--if-exists", "addIfDifferent"];
+    for t in &trailer_args {
+        args.push("--trailer");
+        args.push(t);
+    }
+
+    // Without a trailing newline git glues the trailer onto a subject-only message
+    let input = format!("{}\n", message.trim_end());
+    let out = run_git_with_input(&args, &input)?;
+    Ok(out.trim_end().to_string())
+}
+
+pub(crate) fn print_trailers(trailers: &[Trailer]) {
+    if trailers.is_empty() {
+        return;
+    }
+
+    println!("{}", style("Trailers:").dim());
+    for t in trailers {
+        println!("    {} {}", style("│").dim(), style(t.as_arg()).cyan());
+    }
+    println!();
+}
+
+fn signoff_trailer() -> Result<Trailer> {
+    match (config_get("user.name")?, config_get("user.email")?) {
+        (Some(name), Some(email)) => Ok(Trailer::new("Signed-off-by", format!("{name} <{email}>"))),
+        _ => anyhow::bail!(
+            "Cannot add Signed-off-by trailer: git user.name and user.email must be set (see `orca setup --name <NAME> --email <EMAIL>`)"
+        ),
+    }
+}
+
+fn co_author_trailer(co: &CoAuthor) -> Trailer {
+    Trailer::new("Co-authored-by", format!("{} <{}>", co.name, co.email))
+}
+
+fn issue_trailers(
+    config: &CommitConfig,
+    opts: &TrailerOptions,
+    branch_key: Option<String>,
+) -> Vec<Trailer> {
+    let mut out: Vec<Trailer> = opts
+        .refs
+        .iter()
+        .map(|r| Trailer::new("Refs", normalize_issue_ref(r)))
+        .chain(
+            opts.closes
+                .iter()
+                .map(|c| Trailer::new("Closes", normalize_issue_ref(c))),
+        )
+        .collect();
+
+    // Explicit flags win over whatever the branch name suggests
+    if out.is_empty() {
+        let key = config.issue_trailer.as_deref().and_then(trailer_key);
+        if let (Some(key), Some(issue)) = (key, branch_key) {
+            out.push(Trailer::new(key, issue));
+        }
+    }
+
+    out
+}
+
+fn trailer_key(configured: &str) -> Option<&'static str> {
+    match configured.trim().to_lowercase().as_str() {
+        "refs" | "ref" => Some("Refs"),
+        "closes" | "close" => Some("Closes"),
+        _ => None,
+    }
+}
+
+fn normalize_issue_ref(raw: &str) -> String {
+    let r = raw.trim();
+    if !r.is_empty() && r.chars().all(|c| c.is_ascii_digit()) {
+        format!("#{r}")
+    } else {
+        r.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_override_branch_inference() {
+        let config = CommitConfig {
+            issue_trailer: Some("closes".to_string()),
+            ..Default::default()
+        };
+
+        let from_branch = issue_trailers(&config, &TrailerOptions::default(), Some("#42".to_string()));
+        assert_eq!(from_branch, vec![Trailer::new("Closes", "#42")]);
+
+        let opts = TrailerOptions {
+            refs: vec!["7".to_string()],
+            ..Default::default()
+        };
+        let from_flags = issue_trailers(&config, &opts, Some("#42".to_string()));
+        assert_eq!(from_flags, vec![Trailer::new("Refs", "#7")]);
+    }
+
+    #[test]
+    fn no_issue_trailer_without_config() {
+        let config = CommitConfig::default();
+        let trailers = issue_trailers(&config, &TrailerOptions::default(), Some("#42".to_string()));
+        assert!(trailers.is_empty());
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 
@@ -11,7 +12,8 @@ const DEFAULT_ORCA_BASE_URL: &str = match option_env!("ORCA_DEFAULT_API_BASE_URL
     _ => ORCA_API_HOST,
 };
 
-/// Orca CLI configuration structure
+/// Orca CLI configuration structure, merged from the user config and the
+/// repository's `.orca.toml` and `.git/orca/config.toml` (see `load_config`)
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub(crate) struct OrcaConfig {
     #[serde(default)]
@@ -20,6 +22,28 @@ pub(crate) struct OrcaConfig {
     pub(crate) git: GitConfig,
     #[serde(default)]
     pub(crate) pr_workflow: PrWorkflowConfig,
+    #[serde(default)]
+    pub(crate) commit: CommitConfig,
+    #[serde(default)]
+    pub(crate) issues: IssueConfig,
+    #[serde(default)]
+    pub(crate) cache: CacheConfig,
+    #[serde(default)]
+    pub(crate) diff: DiffConfig,
+    #[serde(default)]
+    pub(crate) audit: AuditConfig,
+    #[serde(default)]
+    pub(crate) models: ModelsConfig,
+    #[serde(default)]
+    pub(crate) secrets: SecretsConfig,
+    #[serde(default)]
+    pub(crate) large_files: LargeFilesConfig,
+    /// Branches orca refuses or warns about pushing, committing or rewriting on
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) protected_branches: Vec<ProtectedBranchRule>,
+    /// `orca safe preflight` profiles keyed by `--operation`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) preflight: BTreeMap<String, PreflightProfile>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -67,6 +91,11 @@ pub(crate) struct GitConfig {
     pub(crate) default_model: Option<String>,
     pub(crate) commit_style: Option<String>,
     pub(crate) language: Option<String>,
+    /// Base branch for publish, tidy, flows and preflight (default "main")
+    pub(crate) base_branch: Option<String>,
+    /// Prefixes `orca branch` and `orca flow start` offer (default feat, fix, chore, hotfix, release)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) branch_types: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -95,6 +124,228 @@ impl Default for PrWorkflowConfig {
     }
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct CommitConfig {
+    /// Append a DCO `Signed-off-by` trailer using the git identity
+    #[serde(default)]
+    pub(crate) signoff: bool,
+    /// Trailer key used for issue references found in the branch name: "Refs" or "Closes"
+    pub(crate) issue_trailer: Option<String>,
+    /// Pairing list; each entry becomes a `Co-authored-by` trailer
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) co_authors: Vec<CoAuthor>,
+    /// Commit body layout with {summary}, {changes}, {impact} and {breaking_changes} placeholders
+    pub(crate) body_template: Option<String>,
+    /// Column to wrap the commit body at (default 72, 0 disables wrapping)
+    pub(crate) wrap_width: Option<usize>,
+    /// Keep the AI description out of the commit body; it is still used for PR text
+    #[serde(default)]
+    pub(crate) omit_description: bool,
+    /// Push the `refs/notes/orca` commit descriptions along with published branches
+    #[serde(default)]
+    pub(crate) sync_notes: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct CoAuthor {
+    pub(crate) name: String,
+    pub(crate) email: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct IssueConfig {
+    /// Add the issue key from the branch name to commit subjects and PR titles.
+    /// Unset means "only for the jira, linear and github style presets".
+    pub(crate) inject: Option<bool>,
+    /// Where to put the key: "prefix" (PROJ-123 feat: ...) or "suffix" (feat: ... (#123))
+    pub(crate) placement: Option<String>,
+    /// Branch-name patterns tried in order; the built-in Jira/Linear and GitHub patterns are used when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) patterns: Vec<IssuePattern>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct IssuePattern {
+    /// Regex matched against the branch name
+    pub(crate) regex: String,
+    /// Replacement built from the captures, e.g. "$1" or "#$1"
+    #[serde(default = "default_issue_format")]
+    pub(crate) format: String,
+}
+
+fn default_issue_format() -> String {
+    "$1".to_string()
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct CacheConfig {
+    /// Maximum number of cached plans kept per repository (default 20)
+    pub(crate) max_entries: Option<usize>,
+    /// Hours before a cached plan expires (default 24)
+    pub(crate) ttl_hours: Option<u64>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct DiffConfig {
+    /// Files larger than this many bytes are sent as stats only (default 100000)
+    pub(crate) max_file_bytes: Option<u64>,
+    /// Gitignore-style patterns never sent to the AI, in addition to `.orcaignore`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) ignore: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct AuditConfig {
+    /// Record every AI request (provider, model, sizes, payload hash) to a local JSON Lines log
+    #[serde(default)]
+    pub(crate) enabled: bool,
+    /// Log location (default: audit.jsonl next to config.toml)
+    pub(crate) path: Option<String>,
+}
+
+/// Model per AI task; unset tasks fall back to `git.default_model`, then the provider default
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct ModelsConfig {
+    pub(crate) commit: Option<String>,
+    pub(crate) release_notes: Option<String>,
+    pub(crate) pr_description: Option<String>,
+    pub(crate) review: Option<String>,
+    pub(crate) conflict: Option<String>,
+}
+
+/// Accepted secret-scan findings
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct SecretsConfig {
+    /// Gitignore-style path patterns whose findings are never reported (e.g. "tests/fixtures/")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) allow_paths: Vec<String>,
+    /// Rule ids, as shown by `orca safe scan`, that are never reported
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) allow_rules: Vec<String>,
+    /// gitleaks config to apply, relative to the repository root (default: .gitleaks.toml if present)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) gitleaks_config: Option<String>,
+}
+
+/// Oversized and non-LFS blob checks
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct LargeFilesConfig {
+    /// Blobs above this many KiB are flagged (default 5120)
+    pub(crate) max_file_size_kb: Option<u64>,
+    /// Let orca push branches with flagged blobs; they are still reported by scans and preflight
+    #[serde(default)]
+    pub(crate) allow_push: bool,
+}
+
+/// One `[[protected_branches]]` entry; a repository layer that sets the list replaces the user's.
+/// Unset actions default to warn for push and commit, block for force-push and rewrite.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ProtectedBranchRule {
+    /// Branch name or glob such as `release/*` (`*` stops at `/`, `**` does not)
+    pub(crate) pattern: String,
+    #[serde(default = "ProtectionAction::warn")]
+    pub(crate) push: ProtectionAction,
+    #[serde(default = "ProtectionAction::block")]
+    pub(crate) force_push: ProtectionAction,
+    /// Commits orca creates (plan apply, squash, fixup) and the pre-commit hook
+    #[serde(default = "ProtectionAction::warn")]
+    pub(crate) commit: ProtectionAction,
+    /// `orca tidy` squash/amend/rebase and `orca git sync --rebase` over local commits
+    #[serde(default = "ProtectionAction::block")]
+    pub(crate) rewrite: ProtectionAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ProtectionAction {
+    Allow,
+    Warn,
+    Block,
+}
+
+impl ProtectionAction {
+    fn warn() -> Self {
+        ProtectionAction::Warn
+    }
+
+    fn block() -> Self {
+        ProtectionAction::Block
+    }
+}
+
+/// Checks `orca safe preflight --operation <name>` runs from `[preflight.<name>]`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct PreflightProfile {
+    /// Built-in checks in run order: secrets, branch-protection, upstream, large-files,
+    /// commit-lint, clean-tree, behind-base. Unset keeps the operation's default checks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) checks: Option<Vec<String>>,
+    /// Severity per built-in check id or command name; errors fail the preflight, warnings only report
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) severity: BTreeMap<String, PreflightSeverity>,
+    /// Base branch for commit-lint and behind-base (default `git.base_branch`)
+    pub(crate) base: Option<String>,
+    /// large-files limit in KiB (default: `[large_files] max_file_size_kb`)
+    pub(crate) max_file_size_kb: Option<u64>,
+    /// Commands run at the same time (default 1)
+    pub(crate) parallel: Option<usize>,
+    /// Shell commands (tests, lints) run from the repository root after the built-in checks
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) commands: Vec<PreflightCommand>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct PreflightCommand {
+    pub(crate) name: String,
+    /// Run with `sh -c` (`cmd /C` on Windows); a non-zero exit fails the check
+    pub(crate) run: String,
+    /// Seconds before the command is killed (default 600)
+    pub(crate) timeout_secs: Option<u64>,
+    #[serde(default)]
+    pub(crate) severity: PreflightSeverity,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum PreflightSeverity {
+    #[default]
+    Error,
+    Warning,
+}
+
+/// Per-repository settings file, committed at the repository root
+pub(crate) const REPO_CONFIG_FILE: &str = ".orca.toml";
+
+/// Uncommitted per-clone overrides, relative to the git common directory
+pub(crate) const REPO_LOCAL_CONFIG_FILE: &str = "orca/config.toml";
+
+/// Sections only read from the user config: they hold credentials and the servers they are sent to
+const USER_ONLY_SECTIONS: &[&str] = &["api"];
+
+/// Where a configuration layer was read from, lowest precedence first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum ConfigLayer {
+    User,
+    Repo,
+    RepoLocal,
+}
+
+/// One configuration file as written, before merging
+#[derive(Debug)]
+pub(crate) struct LayerFile {
+    pub(crate) path: PathBuf,
+    pub(crate) table: toml::Table,
+}
+
+impl LayerFile {
+    /// Value at a dotted key path such as `git.base_branch`
+    pub(crate) fn get(&self, key: &[&str]) -> Option<&toml::Value> {
+        let (first, rest) = key.split_first()?;
+        rest.iter()
+            .try_fold(self.table.get(*first)?, |value, part| value.as_table()?.get(*part))
+    }
+}
+
 /// Get the path to the Orca config file
 /// - Windows: %APPDATA%\orca\config.toml
 /// - Linux/macOS: ~/.config/orca/config.toml
@@ -106,9 +357,92 @@ pub(crate) fn config_file_path() -> Result<PathBuf> {
     Ok(orca_dir.join("config.toml"))
 }
 
-/// Load the Orca configuration from disk
-/// Returns default config if file doesn't exist
+/// Candidate files for every layer, lowest precedence first; repository layers
+/// are left out when the current directory is not inside a work tree
+pub(crate) fn layer_paths() -> Result<Vec<(ConfigLayer, PathBuf)>> {
+    let mut paths = vec![(ConfigLayer::User, config_file_path()?)];
+    if let Ok(root) = crate::git::get_repo_root() {
+        paths.push((ConfigLayer::Repo, root.join(REPO_CONFIG_FILE)));
+        if let Ok(common_dir) = crate::git::git_common_dir() {
+            paths.push((ConfigLayer::RepoLocal, common_dir.join(REPO_LOCAL_CONFIG_FILE)));
+        }
+    }
+    Ok(paths)
+}
+
+/// Read every existing layer; user-only sections are dropped from repository layers
+pub(crate) fn load_layers() -> Result<Vec<LayerFile>> {
+    let mut layers = Vec::new();
+    for (layer, path) in layer_paths()? {
+        if !path.exists() {
+            continue;
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+        // Parse once into the typed config so errors point at the offending file and line
+        toml::from_str::<OrcaConfig>(&content)
+            .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
+        let mut table: toml::Table = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
+
+        if layer != ConfigLayer::User {
+            for section in USER_ONLY_SECTIONS {
+                if table.remove(*section).is_some() {
+                    warn_user_only(section, &path);
+                }
+            }
+        }
+        layers.push(LayerFile { path, table });
+    }
+    Ok(layers)
+}
+
+fn warn_user_only(section: &str, path: &std::path::Path) {
+    static WARNED: std::sync::Once = std::sync::Once::new();
+    WARNED.call_once(|| {
+        eprintln!(
+            "{} Ignoring [{}] in {}: credentials and endpoints are only read from {}",
+            console::style("[!]").yellow().bold(),
+            section,
+            path.display(),
+            config_file_path()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|_| "the user config".to_string())
+        );
+    });
+}
+
+/// Fold layers into one table: nested tables merge key by key, any other value
+/// (including arrays such as `protected_branches`) is replaced by the higher layer
+pub(crate) fn merge_layers(layers: &[LayerFile]) -> Result<OrcaConfig> {
+    fn merge(base: &mut toml::Table, overlay: &toml::Table) {
+        for (key, value) in overlay {
+            match (base.get_mut(key), value) {
+                (Some(toml::Value::Table(existing)), toml::Value::Table(incoming)) => merge(existing, incoming),
+                _ => {
+                    base.insert(key.clone(), value.clone());
+                }
+            }
+        }
+    }
+
+    let mut merged = toml::Table::new();
+    for layer in layers {
+        merge(&mut merged, &layer.table);
+    }
+    toml::Value::Table(merged)
+        .try_into()
+        .context("Failed to combine configuration layers")
+}
+
+/// Effective configuration: repo-local > repo `.orca.toml` > user config > defaults
 pub(crate) fn load_config() -> Result<OrcaConfig> {
+    merge_layers(&load_layers()?)
+}
+
+/// The user's own config file only, for commands that modify and save it
+/// Returns default config if file doesn't exist
+pub(crate) fn load_user_config() -> Result<OrcaConfig> {
     let path = config_file_path()?;
     
     if !path.exists() {
@@ -124,6 +458,25 @@ pub(crate) fn load_config() -> Result<OrcaConfig> {
     Ok(config)
 }
 
+/// Base branch for publish, tidy, flows and preflight when none is given (default "main")
+pub(crate) fn default_base_branch() -> String {
+    load_config()
+        .ok()
+        .and_then(|c| c.git.base_branch)
+        .filter(|b| !b.trim().is_empty())
+        .unwrap_or_else(|| "main".to_string())
+}
+
+/// Branch prefixes from `git.branch_types`, else feat, fix, chore, hotfix, release
+pub(crate) fn branch_types() -> Vec<String> {
+    let configured = load_config().map(|c| c.git.branch_types).unwrap_or_default();
+    if configured.is_empty() {
+        ["feat", "fix", "chore", "hotfix", "release"].map(String::from).to_vec()
+    } else {
+        configured
+    }
+}
+
 /// Save the Orca configuration to disk
 pub(crate) fn save_config(config: &OrcaConfig) -> Result<()> {
     let path = config_file_path()?;
@@ -173,7 +526,7 @@ pub(crate) fn get_api_key(provider: &str) -> Result<String> {
     }
 
     // 2. Check config file
-    let config = load_config()?;
+    let config = load_user_config()?;
     let key = config_key_for_provider(&config, provider);
 
     if let Some(k) = key {
@@ -191,7 +544,7 @@ pub(crate) fn get_api_key(provider: &str) -> Result<String> {
 
 /// Get the active provider name from config
 pub(crate) fn get_provider() -> String {
-    load_config()
+    load_user_config()
         .map(|c| c.api.provider)
         .unwrap_or_else(|_| "gemini".to_string())
 }
@@ -219,7 +572,7 @@ pub(crate) fn get_orca_token() -> Result<String> {
         }
     }
 
-    let config = load_config()?;
+    let config = load_user_config()?;
     if let Some(v) = config.api.orca_token {
         if !v.trim().is_empty() {
             return Ok(v);
@@ -246,8 +599,77 @@ mod tests {
                 default_model: Some("gemini-2.5-flash".to_string()),
                 commit_style: None,
                 language: Some("Vietnamese".to_string()),
+                base_branch: Some("develop".to_string()),
+                branch_types: vec!["feat".to_string(), "spike".to_string()],
             },
             pr_workflow: PrWorkflowConfig::default(),
+            commit: CommitConfig {
+                signoff: true,
+                issue_trailer: Some("Closes".to_string()),
+                co_authors: vec![CoAuthor {
+                    name: "Jane Doe".to_string(),
+                    email: "jane@example.com".to_string(),
+                }],
+                body_template: Some("{summary}\n\n{breaking_changes}".to_string()),
+                wrap_width: Some(80),
+                omit_description: false,
+                sync_notes: true,
+            },
+            issues: IssueConfig {
+                inject: Some(true),
+                placement: None,
+                patterns: vec![IssuePattern {
+                    regex: r"^[a-z]+/gh-(\d+)".to_string(),
+                    format: "#$1".to_string(),
+                }],
+            },
+            cache: CacheConfig {
+                max_entries: Some(5),
+                ttl_hours: None,
+            },
+            diff: DiffConfig {
+                max_file_bytes: Some(50_000),
+                ignore: vec!["*.lock".to_string()],
+            },
+            audit: AuditConfig {
+                enabled: true,
+                path: None,
+            },
+            models: ModelsConfig {
+                release_notes: Some("gpt-4o".to_string()),
+                ..Default::default()
+            },
+            secrets: SecretsConfig {
+                allow_paths: vec!["tests/fixtures/".to_string()],
+                allow_rules: vec![],
+                gitleaks_config: Some("ci/gitleaks.toml".to_string()),
+            },
+            large_files: LargeFilesConfig {
+                max_file_size_kb: Some(20_480),
+                allow_push: false,
+            },
+            protected_branches: vec![ProtectedBranchRule {
+                pattern: "release/*".to_string(),
+                push: ProtectionAction::Block,
+                force_push: ProtectionAction::Block,
+                commit: ProtectionAction::Allow,
+                rewrite: ProtectionAction::Block,
+            }],
+            preflight: BTreeMap::from([(
+                "release".to_string(),
+                PreflightProfile {
+                    checks: Some(vec!["secrets".to_string(), "clean-tree".to_string()]),
+                    severity: BTreeMap::from([("clean-tree".to_string(), PreflightSeverity::Warning)]),
+                    parallel: Some(2),
+                    commands: vec![PreflightCommand {
+                        name: "tests".to_string(),
+                        run: "cargo test".to_string(),
+                        timeout_secs: Some(900),
+                        severity: PreflightSeverity::Error,
+                    }],
+                    ..Default::default()
+                },
+            )]),
         };
 
         let toml_str = toml::to_string(&config).unwrap();
@@ -256,6 +678,56 @@ mod tests {
         assert_eq!(parsed.api.gemini_api_key, Some("test-key".to_string()));
         assert_eq!(parsed.git.default_model, Some("gemini-2.5-flash".to_string()));
         assert_eq!(parsed.git.language, Some("Vietnamese".to_string()));
+        assert_eq!(parsed.git.base_branch.as_deref(), Some("develop"));
+        assert_eq!(parsed.git.branch_types, vec!["feat", "spike"]);
+        assert_eq!(parsed.diff.ignore, vec!["*.lock"]);
+        assert!(parsed.commit.signoff);
+        assert_eq!(parsed.commit.issue_trailer, Some("Closes".to_string()));
+        assert_eq!(parsed.commit.co_authors.len(), 1);
+        assert_eq!(parsed.commit.wrap_width, Some(80));
+        assert!(parsed.commit.sync_notes);
+        assert_eq!(parsed.cache.max_entries, Some(5));
+        assert!(parsed.audit.enabled);
+        assert_eq!(parsed.models.release_notes, Some("gpt-4o".to_string()));
+        assert_eq!(parsed.models.commit, None);
+        assert_eq!(parsed.secrets.allow_paths, vec!["tests/fixtures/"]);
+        assert_eq!(parsed.secrets.gitleaks_config.as_deref(), Some("ci/gitleaks.toml"));
+        assert_eq!(parsed.issues.inject, Some(true));
+        assert_eq!(parsed.issues.patterns[0].format, "#$1");
+        assert_eq!(parsed.large_files.max_file_size_kb, Some(20_480));
+        assert_eq!(parsed.protected_branches[0].push, ProtectionAction::Block);
+        assert_eq!(parsed.protected_branches[0].commit, ProtectionAction::Allow);
+        let release = &parsed.preflight["release"];
+        assert_eq!(release.checks.as_deref().map(|c| c.len()), Some(2));
+        assert_eq!(release.severity["clean-tree"], PreflightSeverity::Warning);
+        assert_eq!(release.commands[0].timeout_secs, Some(900));
+    }
+
+    #[test]
+    fn higher_layers_override_lower_ones() {
+        let layer = |name: &str, content: &str| LayerFile {
+            path: PathBuf::from(name),
+            table: toml::from_str(content).unwrap(),
+        };
+        let layers = vec![
+            layer(
+                "user",
+                "[git]\nlanguage = \"German\"\ncommit_style = \"conventional\"\n\n[diff]\nignore = [\"*.log\"]\n",
+            ),
+            layer("repo", "[git]\ncommit_style = \"gitmoji\"\nbase_branch = \"develop\"\n"),
+            layer("repo-local", "[diff]\nignore = [\"dist/\"]\n"),
+        ];
+
+        let config = merge_layers(&layers).unwrap();
+        assert_eq!(config.git.language.as_deref(), Some("German"));
+        assert_eq!(config.git.commit_style.as_deref(), Some("gitmoji"));
+        assert_eq!(config.git.base_branch.as_deref(), Some("develop"));
+        assert_eq!(config.diff.ignore, vec!["dist/"]);
+        assert_eq!(
+            layers[1].get(&["git", "base_branch"]).and_then(|v| v.as_str()),
+            Some("develop")
+        );
+        assert!(layers[1].get(&["git", "language"]).is_none());
     }
 
     #[test]
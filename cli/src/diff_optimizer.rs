@@ -1,5 +1,8 @@
 use crate::git::run_git;
-use anyhow::Result;
+use anyhow::{Context, Result};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::collections::HashMap;
+use std::io::Read;
 use std::path::Path;
 
 /// Diff mode for AI processing
@@ -13,6 +16,8 @@ pub enum DiffMode {
     Filtered,
     /// Summary only (stats, no patches)
     Summary,
+    /// Staged changes only, as the next commit will contain them
+    Staged,
 }
 
 impl Default for DiffMode {
@@ -28,33 +33,46 @@ pub fn get_diff_for_ai(mode: DiffMode) -> Result<String> {
         DiffMode::Minimal => run_git(&["diff", "--minimal", "--unified=3"]),
         DiffMode::Filtered => get_optimized_diff(),
         DiffMode::Summary => get_diff_summary(),
+        DiffMode::Staged => run_git(&["diff", "--cached", "--minimal", "--unified=3"]),
     }
 }
 
-/// Get diff with binary and large files filtered out
+/// Get diff with binary, generated, ignored and large files reduced to stats
 fn get_optimized_diff() -> Result<String> {
     use crate::plan::files_from_status_porcelain;
     
     // Get status first to see what changed
     let status = run_git(&["status", "--porcelain"])?;
     let files = files_from_status_porcelain(&status);
-    
-    // Filter out binary/large files
-    let text_files: Vec<String> = files
-        .into_iter()
-        .filter(|f| !is_binary_or_large(f))
+
+    let repo_root = crate::git::get_repo_root()?;
+    let filter = DiffFilter::load(&repo_root)?;
+    let omitted = omitted_files(&filter, &repo_root, &files)?;
+
+    let text_files: Vec<&str> = files
+        .iter()
+        .filter(|f| !omitted.iter().any(|(o, _)| o == *f))
+        .map(|s| s.as_str())
         .collect();
     
-    if text_files.is_empty() {
-        return Ok(String::new());
-    }
-    
     // Get diff only for text files, using minimal algorithm
-    let mut diff_args = vec!["diff", "--minimal", "--unified=3", "--"];
-    let file_refs: Vec<&str> = text_files.iter().map(|s| s.as_str()).collect();
-    diff_args.extend(file_refs);
-    
-    run_git(&diff_args)
+    let mut diff = if text_files.is_empty() {
+        String::new()
+    } else {
+        let mut diff_args = vec!["diff", "--minimal", "--unified=3", "--"];
+        diff_args.extend(text_files);
+        run_git(&diff_args)?
+    };
+
+    // Omitted files stay visible to the model, just without their content
+    if !omitted.is_empty() {
+        if !diff.is_empty() && !diff.ends_with('\n') {
+            diff.push('\n');
+        }
+        diff.push_str(&summarize_omitted(&omitted)?);
+    }
+
+    Ok(diff)
 }
 
 /// Get diff summary (stats only, no actual patches)
@@ -72,102 +90,234 @@ fn get_diff_summary() -> Result<String> {
     ))
 }
 
-/// Check if a file should be excluded from diff (binary or too large)
-fn is_binary_or_large(path: &str) -> bool {
-    // Binary file extensions
-    let binary_exts = [
-        ".png", ".jpg", ".jpeg", ".gif", ".bmp", ".ico", ".svg",
-        ".webp", ".pdf", ".zip", ".gz", ".tar", ".7z", ".rar",
-        ".bin", ".exe", ".dll", ".so", ".dylib", ".a", ".o",
-        ".wasm", ".pyc", ".class", ".jar", ".war",
-        ".mp3", ".mp4", ".avi", ".mov", ".wav", ".flac",
-        ".ttf", ".woff", ".woff2", ".eot",
-        ".db", ".sqlite", ".sqlite3",
-    ];
-    
-    // Lock files and generated files
-    let lock_files = [
-        "package-lock.json",
-        "yarn.lock",
-        "pnpm-lock.yaml",
-        "Cargo.lock",
-        "Gemfile.lock",
-        "poetry.lock",
-        "composer.lock",
-    ];
-    
-    // Large auto-generated files
-    let generated_patterns = [
-        "dist/",
-        "build/",
-        "node_modules/",
-        "vendor/",
-        ".min.js",
-        ".min.css",
-        "bundle.js",
-        "bundle.css",
-    ];
-    
-    // Check file extension
-    let path_lower = path.to_lowercase();
-    if binary_exts.iter().any(|ext| path_lower.ends_with(ext)) {
-        return true;
+/// Default size above which a file's content is left out of the diff
+pub(crate) const DEFAULT_MAX_FILE_BYTES: u64 = 100_000;
+
+/// Built-in patterns applied before `.orcaignore` (which can re-include them with `!pattern`)
+const DEFAULT_IGNORE_PATTERNS: &[&str] = &[
+    "package-lock.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "Cargo.lock",
+    "Gemfile.lock",
+    "poetry.lock",
+    "composer.lock",
+    "node_modules/",
+    "/vendor/",
+    "/dist/",
+    "/build/",
+    "*.min.js",
+    "*.min.css",
+];
+
+/// Why a file's content is left out of the AI diff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OmitReason {
+    Ignored,
+    Generated,
+    Binary,
+    Large,
+}
+
+impl OmitReason {
+    fn label(self) -> &'static str {
+        match self {
+            OmitReason::Ignored => "ignored",
+            OmitReason::Generated => "generated",
+            OmitReason::Binary => "binary",
+            OmitReason::Large => "large",
+        }
     }
-    
-    // Check if it's a lock file
-    let path_obj = Path::new(path);
-    if let Some(filename) = path_obj.file_name() {
-        let filename_str = filename.to_string_lossy();
-        if lock_files.iter().any(|lock| filename_str == *lock) {
-            return true;
+}
+
+/// Path-based filtering from the built-in patterns, `diff.ignore`, `.orcaignore` and the size limit
+struct DiffFilter {
+    ignore: Gitignore,
+    max_file_bytes: u64,
+}
+
+impl DiffFilter {
+    fn load(repo_root: &Path) -> Result<Self> {
+        let orcaignore = std::fs::read_to_string(repo_root.join(".orcaignore")).unwrap_or_default();
+        let diff = crate::config::load_config().map(|c| c.diff).unwrap_or_default();
+        let max_file_bytes = diff.max_file_bytes.unwrap_or(DEFAULT_MAX_FILE_BYTES);
+        Self::from_patterns(repo_root, &diff.ignore, &orcaignore, max_file_bytes)
+    }
+
+    fn from_patterns(repo_root: &Path, configured: &[String], orcaignore: &str, max_file_bytes: u64) -> Result<Self> {
+        let mut builder = GitignoreBuilder::new(repo_root);
+        for pattern in DEFAULT_IGNORE_PATTERNS {
+            builder.add_line(None, pattern)?;
         }
+        for pattern in configured {
+            builder
+                .add_line(None, pattern)
+                .with_context(|| format!("Invalid diff.ignore pattern: {}", pattern))?;
+        }
+        for line in orcaignore.lines() {
+            builder
+                .add_line(Some(repo_root.join(".orcaignore")), line)
+                .with_context(|| format!("Invalid .orcaignore pattern: {}", line))?;
+        }
+        Ok(Self {
+            ignore: builder.build()?,
+            max_file_bytes,
+        })
     }
-    
-    // Check generated patterns
-    if generated_patterns.iter().any(|pattern| path.contains(pattern)) {
-        return true;
+
+    fn is_ignored(&self, path: &str) -> bool {
+        self.ignore
+            .matched_path_or_any_parents(path, false)
+            .is_ignore()
     }
-    
-    // Check file size (if file exists)
-    if let Ok(metadata) = std::fs::metadata(path) {
-        // Files larger than 100KB are probably not good for diff
-        if metadata.len() > 100_000 {
-            return true;
+}
+
+/// Files whose content should not be sent, with the reason, in status order
+fn omitted_files(filter: &DiffFilter, repo_root: &Path, files: &[String]) -> Result<Vec<(String, OmitReason)>> {
+    if files.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let attributes = attribute_reasons(files)?;
+    let numstat_binary = numstat_binaries()?;
+
+    let mut omitted = Vec::new();
+    for file in files {
+        let reason = if filter.is_ignored(file) {
+            Some(OmitReason::Ignored)
+        } else if let Some(reason) = attributes.get(file.as_str()) {
+            Some(*reason)
+        } else if numstat_binary.iter().any(|b| b == file) {
+            Some(OmitReason::Binary)
+        } else {
+            content_reason(&repo_root.join(file), filter.max_file_bytes)
+        };
+
+        if let Some(reason) = reason {
+            omitted.push((file.clone(), reason));
         }
     }
-    
-    false
+    Ok(omitted)
+}
+
+/// Honor `linguist-generated`, `-diff` and `binary` from `.gitattributes`
+fn attribute_reasons(files: &[String]) -> Result<HashMap<String, OmitReason>> {
+    let mut args = vec!["check-attr", "-z", "linguist-generated", "diff", "binary", "--"];
+    args.extend(files.iter().map(|s| s.as_str()));
+    let out = run_git(&args)?;
+
+    let mut reasons = HashMap::new();
+    let fields: Vec<&str> = out.split('\0').collect();
+    for chunk in fields.chunks(3) {
+        let [path, attr, value] = chunk else {
+            continue;
+        };
+        let reason = match (*attr, *value) {
+            ("linguist-generated", "set" | "true") => OmitReason::Generated,
+            ("binary", "set") | ("diff", "unset") => OmitReason::Binary,
+            _ => continue,
+        };
+        reasons.entry(path.to_string()).or_insert(reason);
+    }
+    Ok(reasons)
+}
+
+/// Tracked files git itself treats as binary (`-` counts in numstat)
+fn numstat_binaries() -> Result<Vec<String>> {
+    let out = run_git(&["diff", "--numstat"])?;
+    Ok(out
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\t');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some("-"), Some("-"), Some(path)) => Some(path.to_string()),
+                _ => None,
+            }
+        })
+        .collect())
+}
+
+/// Check the working-tree file for size and NUL bytes
+fn content_reason(path: &Path, max_file_bytes: u64) -> Option<OmitReason> {
+    let metadata = std::fs::metadata(path).ok()?;
+    if metadata.len() > max_file_bytes {
+        return Some(OmitReason::Large);
+    }
+
+    let mut head = Vec::with_capacity(8000);
+    let file = std::fs::File::open(path).ok()?;
+    file.take(8000).read_to_end(&mut head).ok()?;
+    if is_binary_content(&head) {
+        return Some(OmitReason::Binary);
+    }
+    None
+}
+
+/// Same heuristic git uses: a NUL byte in the first 8000 bytes
+fn is_binary_content(head: &[u8]) -> bool {
+    head.iter().take(8000).any(|b| *b == 0)
+}
+
+fn summarize_omitted(omitted: &[(String, OmitReason)]) -> Result<String> {
+    let numstat = run_git(&["diff", "--numstat"])?;
+    let counts: HashMap<&str, (&str, &str)> = numstat
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\t');
+            Some((parts.next()?, parts.next()?, parts.next()?))
+        })
+        .map(|(added, deleted, path)| (path, (added, deleted)))
+        .collect();
+
+    let mut out = vec!["FILES OMITTED FROM DIFF (stats only):".to_string()];
+    for (path, reason) in omitted {
+        let stats = match counts.get(path.as_str()) {
+            Some(("-", "-")) => "binary change".to_string(),
+            Some((added, deleted)) => format!("+{} -{}", added, deleted),
+            None => "new or untracked file".to_string(),
+        };
+        out.push(format!("- {} ({}, {})", path, stats, reason.label()));
+    }
+    Ok(out.join("\n") + "\n")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn filter(orcaignore: &str) -> DiffFilter {
+        DiffFilter::from_patterns(Path::new("/repo"), &[], orcaignore, DEFAULT_MAX_FILE_BYTES).unwrap()
+    }
+
     #[test]
-    fn test_is_binary_or_large_detects_images() {
-        assert!(is_binary_or_large("logo.png"));
-        assert!(is_binary_or_large("photo.jpg"));
-        assert!(is_binary_or_large("icon.svg"));
+    fn default_patterns_skip_lock_files_and_root_build_dirs() {
+        let f = filter("");
+        assert!(f.is_ignored("package-lock.json"));
+        assert!(f.is_ignored("crates/foo/Cargo.lock"));
+        assert!(f.is_ignored("dist/bundle.js"));
+        assert!(f.is_ignored("web/node_modules/lodash/index.js"));
+        assert!(f.is_ignored("app.min.js"));
     }
 
     #[test]
-    fn test_is_binary_or_large_detects_lock_files() {
-        assert!(is_binary_or_large("package-lock.json"));
-        assert!(is_binary_or_large("Cargo.lock"));
-        assert!(is_binary_or_large("yarn.lock"));
+    fn nested_build_dirs_and_sources_are_kept() {
+        let f = filter("");
+        assert!(!f.is_ignored("src/build/mod.rs"));
+        assert!(!f.is_ignored("main.rs"));
+        assert!(!f.is_ignored("README.md"));
+        assert!(!f.is_ignored("logo.png"));
     }
 
     #[test]
-    fn test_is_binary_or_large_allows_source_files() {
-        assert!(!is_binary_or_large("main.rs"));
-        assert!(!is_binary_or_large("index.ts"));
-        assert!(!is_binary_or_large("README.md"));
+    fn orcaignore_adds_and_reincludes_patterns() {
+        let f = filter("# generated clients\nsrc/gen/**\n!Cargo.lock\n");
+        assert!(f.is_ignored("src/gen/api.rs"));
+        assert!(!f.is_ignored("Cargo.lock"));
     }
 
     #[test]
-    fn test_is_binary_or_large_detects_generated() {
-        assert!(is_binary_or_large("dist/bundle.js"));
-        assert!(is_binary_or_large("node_modules/lodash/index.js"));
-        assert!(is_binary_or_large("app.min.js"));
+    fn binary_detection_uses_content() {
+        assert!(is_binary_content(b"\x89PNG\r\n\x1a\n\0\0\0"));
+        assert!(!is_binary_content("fn main() {}\n".as_bytes()));
     }
 }
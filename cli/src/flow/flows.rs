@@ -4,6 +4,8 @@ use anyhow::Result;
 use console::style;
 use std::path::PathBuf;
 
+pub(crate) use super::flows_error::ExitCodeError;
+
 pub(crate) async fn generate_plan(
     model: &str,
     status: &str,
@@ -27,7 +29,17 @@ pub(crate) fn print_friendly_error(err: &anyhow::Error) {
     super::flows_error::print_friendly_error(err);
 }
 
-pub(crate) async fn run_commit_flow(confirm: bool, dry_run: bool, model: &str, commit_style: Option<String>) -> Result<()> {
+pub(crate) fn exit_code(err: &anyhow::Error) -> i32 {
+    super::flows_error::exit_code(err)
+}
+
+pub(crate) async fn run_commit_flow(
+    confirm: bool,
+    dry_run: bool,
+    model: &str,
+    commit_style: Option<String>,
+    trailer_opts: &crate::commit_trailers::TrailerOptions,
+) -> Result<()> {
     ensure_git_repo()?;
 
     super::flows_error::print_flow_header("[orca commit]");
@@ -55,6 +67,12 @@ pub(crate) async fn run_commit_flow(confirm: bool, dry_run: bool, model: &str, c
     }
 
     let changed_files = files_from_status_porcelain(&status);
+    let trailers = crate::commit_trailers::resolve_trailers(trailer_opts)?;
+    let style_preset = commit_style
+        .clone()
+        .or_else(|| crate::config::load_config().ok().and_then(|c| c.git.commit_style))
+        .as_deref()
+        .and_then(crate::cli::CommitStylePreset::from_style);
 
     let spinner_msg = if crate::config::get_provider() == "orca" {
         "Asking Orca Server to analyze changes and propose commit plan...".to_string()
@@ -72,6 +90,7 @@ pub(crate) async fn run_commit_flow(confirm: bool, dry_run: bool, model: &str, c
 
     println!("\n{}", style("Proposed Plan:").bold().cyan());
     print_plan_human(&plan);
+    crate::commit_trailers::print_trailers(&trailers);
 
     if dry_run {
         return Ok(());
@@ -87,7 +106,7 @@ pub(crate) async fn run_commit_flow(confirm: bool, dry_run: bool, model: &str, c
     }
 
     let pb = spinner("Applying plan (running git add and commit)...");
-    apply_plan(&mut plan, None)?; // TODO: Extract preset from commit_style string
+    apply_plan(&mut plan, style_preset, &trailers)?;
     pb.finish_and_clear();
     eprintln!(
         "{} {}",
@@ -148,8 +167,9 @@ pub(crate) async fn run_apply_flow(
     branch: Option<&str>,
     base: &str,
     pr: bool,
+    trailer_opts: &crate::commit_trailers::TrailerOptions,
 ) -> Result<()> {
-    super::flows_apply::run_apply_flow(file, confirm, dry_run, push, publish, branch, base, pr).await
+    super::flows_apply::run_apply_flow(file, confirm, dry_run, push, publish, branch, base, pr, trailer_opts).await
 }
 
 pub(crate) async fn run_doctor_flow() -> Result<()> {
@@ -291,14 +311,97 @@ pub(crate) async fn run_stack_publish_flow(pr: bool, yes: bool, yes_pr: bool) ->
 }
 
 // Safety flows
-pub(crate) async fn run_safe_scan_flow(all: bool) -> Result<()> {
-    super::flows_safe::run_safe_scan_flow(all).await
+pub(crate) async fn run_safe_scan_flow(
+    target: crate::secret_scan::ScanTarget,
+    min_confidence: crate::secret_rules::Confidence,
+    update_baseline: bool,
+    format: crate::cli::ReportFormat,
+) -> Result<()> {
+    super::flows_safe::run_safe_scan_flow(target, min_confidence, update_baseline, format).await
+}
+
+pub(crate) async fn run_safe_large_files_flow(
+    target: crate::secret_scan::ScanTarget,
+    max_size_kb: Option<u64>,
+    format: crate::cli::ReportFormat,
+) -> Result<()> {
+    super::flows_safe::run_safe_large_files_flow(target, max_size_kb, format).await
 }
 
 pub(crate) async fn run_safe_preflight_flow(
     operation: &str,
     protection: Option<&str>,
+    format: crate::cli::ReportFormat,
 ) -> Result<()> {
-    super::flows_safe::run_safe_preflight_flow(operation, protection).await
+    super::flows_safe::run_safe_preflight_flow(operation, protection, format).await
 }
 
+// Git hooks flows
+pub(crate) async fn run_hooks_install_flow(hooks: Vec<crate::hooks::HookKind>, ai_draft: bool) -> Result<()> {
+    super::flows_hooks::run_hooks_install_flow(hooks, ai_draft).await
+}
+
+pub(crate) async fn run_hooks_uninstall_flow() -> Result<()> {
+    super::flows_hooks::run_hooks_uninstall_flow().await
+}
+
+pub(crate) async fn run_hooks_status_flow() -> Result<()> {
+    super::flows_hooks::run_hooks_status_flow().await
+}
+
+pub(crate) async fn run_hook(hook: crate::hooks::HookKind, args: &[String]) -> Result<()> {
+    super::flows_hooks::run_hook(hook, args).await
+}
+
+// Pairing (co-author) flows
+pub(crate) async fn run_pair_add_flow(name: &str, email: &str) -> Result<()> {
+    super::flows_pair::run_pair_add_flow(name, email).await
+}
+
+pub(crate) async fn run_pair_remove_flow(who: &str) -> Result<()> {
+    super::flows_pair::run_pair_remove_flow(who).await
+}
+
+pub(crate) async fn run_pair_list_flow() -> Result<()> {
+    super::flows_pair::run_pair_list_flow().await
+}
+
+pub(crate) async fn run_pair_clear_flow() -> Result<()> {
+    super::flows_pair::run_pair_clear_flow().await
+}
+
+pub(crate) async fn run_explain_flow(commit: &str) -> Result<()> {
+    super::flows_notes::run_explain_flow(commit).await
+}
+
+pub(crate) async fn run_notes_push_flow(remote: &str) -> Result<()> {
+    super::flows_notes::run_notes_push_flow(remote).await
+}
+
+pub(crate) async fn run_notes_fetch_flow(remote: &str) -> Result<()> {
+    super::flows_notes::run_notes_fetch_flow(remote).await
+}
+
+pub(crate) async fn run_cache_list_flow() -> Result<()> {
+    super::flows_cache::run_cache_list_flow().await
+}
+
+pub(crate) async fn run_cache_show_flow(key: &str) -> Result<()> {
+    super::flows_cache::run_cache_show_flow(key).await
+}
+
+pub(crate) async fn run_cache_clear_flow() -> Result<()> {
+    super::flows_cache::run_cache_clear_flow().await
+}
+
+pub(crate) async fn run_cache_prune_flow() -> Result<()> {
+    super::flows_cache::run_cache_prune_flow().await
+}
+
+pub(crate) async fn run_prompts_show_flow(name: Option<&str>) -> Result<()> {
+    super::flows_prompts::run_prompts_show_flow(name).await
+}
+
+pub(crate) async fn run_prompts_init_flow(global: bool, force: bool) -> Result<()> {
+    super::flows_prompts::run_prompts_init_flow(global, force).await
+}
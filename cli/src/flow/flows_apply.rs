@@ -19,6 +19,7 @@ pub(crate) async fn run_apply_flow(
     branch: Option<&str>,
     base: &str,
     pr: bool,
+    trailer_opts: &crate::commit_trailers::TrailerOptions,
 ) -> Result<()> {
     // Check if user has Pro/Team plan when using --publish
     if publish {
@@ -41,9 +42,11 @@ pub(crate) async fn run_apply_flow(
     let status = run_git(&["status", "--porcelain"])?;
     let changed_files = files_from_status_porcelain(&status);
     normalize_plan_files(&mut plan, &changed_files);
+    let trailers = crate::commit_trailers::resolve_trailers(trailer_opts)?;
 
     println!("\n{}", style("Plan to Apply:").bold().cyan());
     print_plan_human(&plan);
+    crate::commit_trailers::print_trailers(&trailers);
 
     if dry_run {
         return Ok(());
@@ -59,7 +62,13 @@ pub(crate) async fn run_apply_flow(
     }
 
     let pb = spinner("Applying plan (running git add and commit)...");
-    apply_plan(&mut plan, None)?; // TODO: Pass style preset from plan metadata
+    // Plans don't record their style, so fall back to the configured one
+    let style_preset = crate::config::load_config()
+        .ok()
+        .and_then(|c| c.git.commit_style)
+        .as_deref()
+        .and_then(crate::cli::CommitStylePreset::from_style);
+    apply_plan(&mut plan, style_preset, &trailers)?;
     pb.finish_and_clear();
     eprintln!("{} {}", style("[✓]").green().bold(), style("Commits created successfully").green());
 
@@ -83,9 +83,16 @@ pub(crate) async fn run_branch_new_flow(
 
     flows_error::print_flow_header("[orca branch new]");
     
+    // Normalize type (feature -> feat, bugfix -> fix) unless the configured types list it as is
+    let valid_types = crate::config::branch_types();
+    let normalized_type = match branch_type {
+        "feature" if !valid_types.iter().any(|t| t == "feature") => "feat",
+        "bugfix" if !valid_types.iter().any(|t| t == "bugfix") => "fix",
+        _ => branch_type,
+    };
+
     // Validate branch type
-    let valid_types = ["feat", "feature", "fix", "bugfix", "chore", "hotfix", "release"];
-    if !valid_types.contains(&branch_type) {
+    if !valid_types.iter().any(|t| t == normalized_type) {
         anyhow::bail!(
             "Invalid branch type '{}'. Valid types: {}",
             branch_type,
@@ -93,13 +100,6 @@ pub(crate) async fn run_branch_new_flow(
         );
     }
     
-    // Normalize type (feature -> feat, bugfix -> fix)
-    let normalized_type = match branch_type {
-        "feature" => "feat",
-        "bugfix" => "fix",
-        _ => branch_type,
-    };
-    
     // Create branch name with convention
     let branch_name = format!("{}/{}", normalized_type, name);
     
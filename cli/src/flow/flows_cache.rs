@@ -0,0 +1,107 @@
+use crate::commit_cache::{clear_cache, find_entry, is_expired, list_entries, prune_cache};
+use crate::git::ensure_git_repo;
+use crate::plan::print_plan_human;
+use anyhow::Result;
+use console::style;
+use super::flows_error;
+
+/// List cached plans for this repository
+pub(crate) async fn run_cache_list_flow() -> Result<()> {
+    ensure_git_repo()?;
+    flows_error::print_flow_header("[orca cache list]");
+
+    let entries = list_entries()?;
+    if entries.is_empty() {
+        println!("\n{}", style("No cached plans").dim());
+        return Ok(());
+    }
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs();
+
+    println!();
+    for entry in &entries {
+        let age = format_age(now.saturating_sub(entry.created_at));
+        let status = if is_expired(entry) {
+            style("expired").red()
+        } else {
+            style("valid").green()
+        };
+        println!(
+            "  {}  {:>8}  {}  {}  {}",
+            style(&entry.key[..12]).yellow(),
+            age,
+            status,
+            style(&entry.model).cyan(),
+            style(format!("{} commits", entry.plan.commits.len())).dim()
+        );
+        if let Some(s) = &entry.style {
+            let first_line = s.lines().next().unwrap_or("");
+            println!("  {}  {} {}", " ".repeat(12), style("style:").dim(), style(first_line).dim());
+        }
+    }
+
+    println!(
+        "\n{} {}",
+        style("Hint:").cyan().bold(),
+        style("orca cache show <KEY>").cyan()
+    );
+    Ok(())
+}
+
+/// Show a cached plan
+pub(crate) async fn run_cache_show_flow(key: &str) -> Result<()> {
+    ensure_git_repo()?;
+    flows_error::print_flow_header("[orca cache show]");
+
+    let entry = find_entry(key)?;
+    println!("\n{} {}", style("Key:").bold(), style(&entry.key).yellow());
+    println!("{} {}", style("Model:").bold(), entry.model);
+    if let Some(s) = &entry.style {
+        println!("{} {}", style("Style:").bold(), s);
+    }
+    if let Some(l) = &entry.language {
+        println!("{} {}", style("Language:").bold(), l);
+    }
+    print_plan_human(&entry.plan);
+
+    Ok(())
+}
+
+/// Remove every cached plan
+pub(crate) async fn run_cache_clear_flow() -> Result<()> {
+    ensure_git_repo()?;
+    flows_error::print_flow_header("[orca cache clear]");
+
+    let removed = clear_cache()?;
+    println!(
+        "\n{} {}",
+        style("[✓]").green().bold(),
+        style(format!("Removed {} cached plan(s)", removed)).green()
+    );
+    Ok(())
+}
+
+/// Remove expired plans and those beyond the configured limit
+pub(crate) async fn run_cache_prune_flow() -> Result<()> {
+    ensure_git_repo()?;
+    flows_error::print_flow_header("[orca cache prune]");
+
+    let removed = prune_cache()?;
+    println!(
+        "\n{} {}",
+        style("[✓]").green().bold(),
+        style(format!("Pruned {} cached plan(s)", removed)).green()
+    );
+    Ok(())
+}
+
+fn format_age(secs: u64) -> String {
+    match secs {
+        s if s < 60 => format!("{}s ago", s),
+        s if s < 3600 => format!("{}m ago", s / 60),
+        s if s < 86_400 => format!("{}h ago", s / 3600),
+        s => format!("{}d ago", s / 86_400),
+    }
+}
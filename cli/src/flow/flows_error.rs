@@ -16,6 +16,41 @@ pub(crate) fn print_no_remote_guidance() {
     );
 }
 
+/// An error that should end the process with a specific exit status.
+/// Display and the cause chain are those of the wrapped error.
+#[derive(Debug)]
+pub(crate) struct ExitCodeError {
+    code: i32,
+    error: anyhow::Error,
+}
+
+impl ExitCodeError {
+    /// Tag `error` with `code`, unless it already carries an exit status
+    pub(crate) fn wrap(code: i32, error: anyhow::Error) -> anyhow::Error {
+        if error.is::<ExitCodeError>() {
+            return error;
+        }
+        anyhow::Error::new(ExitCodeError { code, error })
+    }
+}
+
+impl std::fmt::Display for ExitCodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for ExitCodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// Process exit status for a failed command: 1 unless the error carries its own
+pub(crate) fn exit_code(err: &anyhow::Error) -> i32 {
+    err.downcast_ref::<ExitCodeError>().map_or(1, |e| e.code)
+}
+
 pub(crate) fn print_friendly_error(err: &anyhow::Error) {
     let msg = err.to_string();
     eprintln!("{} {}", style("Error:").red().bold(), style(&msg).red());
@@ -17,14 +17,14 @@ pub(crate) async fn run_flow_start(
     let selected_type = if let Some(t) = flow_type {
         t.to_string()
     } else {
-        let types = vec!["feat", "fix", "chore", "hotfix", "release"];
+        let types = crate::config::branch_types();
         let selection = Select::new()
             .with_prompt("Select flow type")
             .items(&types)
             .default(0)
             .interact()
             .context("Failed to read selection")?;
-        types[selection].to_string()
+        types[selection].clone()
     };
     
     // Determine flow name (interactive if not provided)
@@ -109,7 +109,7 @@ pub(crate) async fn run_flow_finish(push: bool, pr: bool, yes: bool, _yes_pr: bo
     
     if push {
         // Use the existing publish flow
-        crate::flow::flows::run_publish_current_flow(None, "main", pr, None, false, true).await?;
+        crate::flow::flows::run_publish_current_flow(None, &crate::config::default_base_branch(), pr, None, false, true).await?;
     } else if pr {
         println!(
             "\n{} {}",
@@ -2,6 +2,7 @@ use crate::git::{
     current_branch, ensure_git_repo, fetch_remote, get_remote_name, is_working_tree_clean,
     merge_upstream, rebase_upstream, run_git, upstream_ahead_behind, upstream_ref,
 };
+use crate::branch_policy::ProtectedOperation;
 use anyhow::Result;
 use console::style;
 use super::flows_error;
@@ -196,6 +197,17 @@ pub(crate) async fn run_git_sync_flow(rebase: bool, yes: bool) -> Result<()> {
             println!("\n{}", style("Already up to date ✓").green());
             return Ok(());
         }
+
+        // A fast-forward leaves local history alone; otherwise local commits are
+        // replayed (rebase) or joined by a merge commit
+        if ahead > 0 {
+            let operation = if rebase {
+                ProtectedOperation::Rewrite
+            } else {
+                ProtectedOperation::Commit
+            };
+            crate::branch_policy::enforce(&branch, operation)?;
+        }
     }
     
     // Perform merge or rebase
@@ -0,0 +1,265 @@
+use super::flows_error;
+use crate::branch_policy::{enforce_current, ProtectedOperation};
+use crate::cli::{CommitStylePreset, ReportFormat};
+use crate::commit_validator::CommitMessageValidator;
+use crate::git::{ensure_git_repo, run_git};
+use crate::hooks::{
+    configured_hooks_path, hook_state, hooks_dir, install_hook, uninstall_hook, HookKind, HookState,
+    InstallOutcome, UninstallOutcome,
+};
+use crate::secret_rules::Confidence;
+use crate::secret_scan::ScanTarget;
+use anyhow::{Context, Result};
+use console::style;
+use std::path::Path;
+
+/// Install managed hooks; `hooks` empty means the default set
+pub(crate) async fn run_hooks_install_flow(hooks: Vec<HookKind>, ai_draft: bool) -> Result<()> {
+    ensure_git_repo()?;
+    flows_error::print_flow_header("[orca hooks install]");
+
+    let mut selected = if hooks.is_empty() {
+        HookKind::DEFAULT.to_vec()
+    } else {
+        hooks
+    };
+    if ai_draft && !selected.contains(&HookKind::PrepareCommitMsg) {
+        selected.push(HookKind::PrepareCommitMsg);
+    }
+
+    let dir = hooks_dir()?;
+    let orca_bin = std::env::current_exe()
+        .ok()
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_else(|| "orca".to_string());
+
+    print_hooks_dir(&dir);
+    for kind in HookKind::ALL.into_iter().filter(|k| selected.contains(k)) {
+        let note = match install_hook(&dir, kind, &orca_bin)? {
+            InstallOutcome::Installed => "installed".to_string(),
+            InstallOutcome::Updated => "updated".to_string(),
+            InstallOutcome::Chained => format!("installed; existing hook kept as {}.orca-chained", kind.file_name()),
+        };
+        println!(
+            "  {} {} ({}) - {}",
+            style("[✓]").green().bold(),
+            style(kind.file_name()).cyan(),
+            kind.description(),
+            note
+        );
+    }
+
+    println!(
+        "\n{} Hooks run for every commit and push, including from IDEs. Bypass once with {}",
+        style("Hint:").yellow().bold(),
+        style("--no-verify").cyan()
+    );
+    Ok(())
+}
+
+pub(crate) async fn run_hooks_uninstall_flow() -> Result<()> {
+    ensure_git_repo()?;
+    flows_error::print_flow_header("[orca hooks uninstall]");
+
+    let dir = hooks_dir()?;
+    print_hooks_dir(&dir);
+    for kind in HookKind::ALL {
+        match uninstall_hook(&dir, kind)? {
+            UninstallOutcome::Removed => println!(
+                "  {} {} removed",
+                style("[✓]").green().bold(),
+                style(kind.file_name()).cyan()
+            ),
+            UninstallOutcome::Restored => println!(
+                "  {} {} removed; previous hook restored",
+                style("[✓]").green().bold(),
+                style(kind.file_name()).cyan()
+            ),
+            UninstallOutcome::NotManaged => println!(
+                "  {} {} not installed by orca, left in place",
+                style("[-]").dim(),
+                kind.file_name()
+            ),
+            UninstallOutcome::Missing => {}
+        }
+    }
+    Ok(())
+}
+
+pub(crate) async fn run_hooks_status_flow() -> Result<()> {
+    ensure_git_repo()?;
+    flows_error::print_flow_header("[orca hooks status]");
+
+    let dir = hooks_dir()?;
+    print_hooks_dir(&dir);
+    for kind in HookKind::ALL {
+        let (mark, state) = match hook_state(&dir, kind) {
+            HookState::Managed { chained: false } => (style("[✓]").green().bold(), "installed".to_string()),
+            HookState::Managed { chained: true } => (
+                style("[✓]").green().bold(),
+                format!("installed, runs {}.orca-chained first", kind.file_name()),
+            ),
+            HookState::Foreign => (
+                style("[!]").yellow().bold(),
+                "another hook is installed; `orca hooks install` will chain it".to_string(),
+            ),
+            HookState::Missing => (style("[-]").dim(), "not installed".to_string()),
+        };
+        println!(
+            "  {} {:<20} {} ({})",
+            mark,
+            kind.file_name(),
+            state,
+            style(kind.description()).dim()
+        );
+    }
+    Ok(())
+}
+
+fn print_hooks_dir(dir: &Path) {
+    let source = if configured_hooks_path().is_some() {
+        " (core.hooksPath)"
+    } else {
+        ""
+    };
+    println!(
+        "\n{} {}{}\n",
+        style("Hooks directory:").bold(),
+        style(dir.display()).cyan(),
+        style(source).dim()
+    );
+}
+
+/// Entry point of the installed hook scripts; `args` are the ones git passed to the hook
+pub(crate) async fn run_hook(kind: HookKind, args: &[String]) -> Result<()> {
+    match kind {
+        HookKind::PreCommit => {
+            enforce_current(ProtectedOperation::Commit)?;
+            super::flows_safe::run_safe_scan_flow(ScanTarget::Staged, Confidence::Medium, false, ReportFormat::Text)
+                .await
+        }
+        HookKind::CommitMsg => {
+            let file = args.first().context("commit-msg hook expects the message file")?;
+            check_commit_message(Path::new(file))
+        }
+        HookKind::PrepareCommitMsg => {
+            let file = args.first().context("prepare-commit-msg hook expects the message file")?;
+            // Only a plain `git commit`: no -m/-F, template, merge, squash or amend
+            if args.get(1).is_some_and(|source| !source.is_empty()) {
+                return Ok(());
+            }
+            // A failed draft must never block the commit
+            if let Err(e) = draft_commit_message(Path::new(file)).await {
+                eprintln!("{} AI draft skipped: {}", style("[!]").yellow().bold(), e);
+            }
+            Ok(())
+        }
+        HookKind::PrePush => super::flows_safe::run_safe_preflight_flow("push", None, ReportFormat::Text).await,
+    }
+}
+
+fn style_preset() -> Option<CommitStylePreset> {
+    crate::config::load_config()
+        .ok()
+        .and_then(|c| c.git.commit_style)
+        .as_deref()
+        .and_then(CommitStylePreset::from_style)
+}
+
+/// Lint the message git is about to commit; only validation errors block
+fn check_commit_message(file: &Path) -> Result<()> {
+    let raw = std::fs::read_to_string(file).with_context(|| format!("Failed to read {}", file.display()))?;
+    let message = strip_comments(&raw, comment_char());
+
+    // Messages git or `--fixup`/`--squash` generate are not the author's to fix
+    let generated = ["Merge ", "Revert \"", "fixup! ", "squash! ", "amend! "];
+    if message.is_empty() || generated.iter().any(|p| message.starts_with(p)) {
+        return Ok(());
+    }
+
+    let validator = CommitMessageValidator::new(style_preset());
+    let validation = validator.validate(&message);
+    validator.print_validation(&message, &validation);
+    if !validation.is_valid {
+        anyhow::bail!("Commit message rejected by orca");
+    }
+    Ok(())
+}
+
+/// The message without git's comment lines and anything below the scissors line
+fn strip_comments(raw: &str, comment: char) -> String {
+    let scissors = format!("{} ------------------------ >8 ------------------------", comment);
+    raw.lines()
+        .take_while(|line| *line != scissors)
+        .filter(|line| !line.starts_with(comment))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+fn comment_char() -> char {
+    run_git(&["config", "--get", "core.commentChar"])
+        .ok()
+        .and_then(|v| {
+            let v = v.trim();
+            let mut chars = v.chars();
+            // "auto" and multi-character values fall back to the default
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c),
+                _ => None,
+            }
+        })
+        .unwrap_or('#')
+}
+
+/// Ask the AI for a message describing the staged changes and put it above git's template
+async fn draft_commit_message(file: &Path) -> Result<()> {
+    let existing = std::fs::read_to_string(file).with_context(|| format!("Failed to read {}", file.display()))?;
+    if !strip_comments(&existing, comment_char()).is_empty() {
+        return Ok(());
+    }
+
+    let status = run_git(&["diff", "--cached", "--name-status"])?;
+    if status.trim().is_empty() {
+        return Ok(());
+    }
+    let diff = crate::diff_optimizer::get_diff_for_ai(crate::diff_optimizer::DiffMode::Staged)?;
+    let log = run_git(&["log", "-n", "20", "--pretty=oneline"]).unwrap_or_default();
+    let model = crate::ai::models::resolve_model(crate::ai::models::ModelTask::Commit, None);
+
+    let spinner = super::flows_spinner::spinner("Drafting commit message...");
+    let plan = super::flows_plan::generate_plan(&model, &status, &diff, &log, None).await;
+    spinner.finish_and_clear();
+    let plan = plan?;
+
+    // Everything staged goes into this one commit; extra groups become body bullets
+    let mut commits = plan.commits.iter();
+    let first = commits.next().context("AI returned no commit message")?;
+    let validator = CommitMessageValidator::new(style_preset());
+    let mut draft = validator.auto_sanitize(&first.message);
+    let others: Vec<String> = commits.map(|c| format!("- {}", c.message.trim())).collect();
+    if !others.is_empty() {
+        draft = format!("{}\n\n{}", draft, others.join("\n"));
+    } else if let Some(desc) = &first.description {
+        let config = crate::config::load_config().unwrap_or_default();
+        if let Some(body) = crate::commit_body::render_commit_body(&config.commit, desc) {
+            draft = format!("{}\n\n{}", draft, body);
+        }
+    }
+
+    std::fs::write(file, format!("{}\n{}", draft, existing))
+        .with_context(|| format!("Failed to write {}", file.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_comments;
+
+    #[test]
+    fn strips_comments_and_verbose_diff() {
+        let raw = "feat: add hooks\n\nBody line\n# Please enter the commit message\n# ------------------------ >8 ------------------------\ndiff --git a/x b/x\n";
+        assert_eq!(strip_comments(raw, '#'), "feat: add hooks\n\nBody line");
+        assert_eq!(strip_comments("; only a comment\n", ';'), "");
+    }
+}
@@ -195,7 +195,7 @@ pub(crate) async fn fetch_user_info() -> Result<UserMeResponse> {
 pub(crate) async fn run_login_flow() -> Result<()> {
     println!("{}", style("[orca login]").bold().cyan());
 
-    let mut config = crate::config::load_config().unwrap_or_default();
+    let mut config = crate::config::load_user_config().unwrap_or_default();
     
     // Check if already logged in and token is valid
     if config.api.provider == "orca" && config.api.orca_token.is_some() {
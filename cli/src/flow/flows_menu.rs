@@ -88,7 +88,7 @@ fn show_config() -> Result<()> {
 
 /// Switch provider
 fn switch_provider() -> Result<()> {
-    let mut config = crate::config::load_config()?;
+    let mut config = crate::config::load_user_config()?;
     let current = config.api.provider.clone();
     
     crate::ui::menu::print_section_header("SWITCH AI PROVIDER");
@@ -164,7 +164,7 @@ fn switch_provider() -> Result<()> {
 
 /// Logout from Orca
 fn logout() -> Result<()> {
-    let mut config = crate::config::load_config()?;
+    let mut config = crate::config::load_user_config()?;
     
     if config.api.orca_token.is_none() {
         crate::ui::menu::print_warning("Not logged in to Orca server");
@@ -0,0 +1,66 @@
+use crate::commit_notes::{fetch_notes, push_notes, read_note, NOTES_REF};
+use crate::git::{ensure_git_repo, run_git};
+use anyhow::Result;
+use console::style;
+use super::flows_error;
+
+/// Show the AI description stored in the orca note of a commit
+pub(crate) async fn run_explain_flow(commit: &str) -> Result<()> {
+    ensure_git_repo()?;
+    flows_error::print_flow_header("[orca explain]");
+
+    let hash = run_git(&["rev-parse", "--verify", &format!("{}^{{commit}}", commit)])?;
+    let hash = hash.trim();
+    let subject = run_git(&["log", "-1", "--pretty=format:%s", hash])?;
+
+    println!(
+        "\n{} {}",
+        style(&hash[..hash.len().min(12)]).yellow(),
+        style(subject.trim()).bold().white()
+    );
+
+    match read_note(hash) {
+        Some(desc) => crate::plan::print_description(&desc),
+        None => {
+            println!("\n{}", style("No orca description stored for this commit").dim());
+            println!(
+                "\n{} {}",
+                style("Hint:").cyan().bold(),
+                style("orca notes fetch  (get descriptions pushed by teammates)").cyan()
+            );
+        }
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Push the orca notes ref to a remote
+pub(crate) async fn run_notes_push_flow(remote: &str) -> Result<()> {
+    ensure_git_repo()?;
+    flows_error::print_flow_header("[orca notes push]");
+
+    push_notes(remote)?;
+    println!(
+        "\n{} {}",
+        style("[✓]").green().bold(),
+        style(format!("Pushed {} to {}", NOTES_REF, remote)).green()
+    );
+
+    Ok(())
+}
+
+/// Fetch the orca notes ref from a remote and merge it into the local one
+pub(crate) async fn run_notes_fetch_flow(remote: &str) -> Result<()> {
+    ensure_git_repo()?;
+    flows_error::print_flow_header("[orca notes fetch]");
+
+    fetch_notes(remote)?;
+    println!(
+        "\n{} {}",
+        style("[✓]").green().bold(),
+        style(format!("Merged {} from {}", NOTES_REF, remote)).green()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use crate::config::{load_config, load_user_config, save_config, CoAuthor};
+use anyhow::Result;
+use console::style;
+use super::flows_error;
+
+/// Add a co-author to the pairing list
+pub(crate) async fn run_pair_add_flow(name: &str, email: &str) -> Result<()> {
+    flows_error::print_flow_header("[orca pair add]");
+
+    let name = name.trim();
+    let email = email.trim().trim_start_matches('<').trim_end_matches('>');
+    if name.is_empty() || !email.contains('@') {
+        anyhow::bail!("Invalid co-author. Usage: orca pair add \"<NAME>\" <EMAIL>");
+    }
+
+    let mut config = load_user_config()?;
+    if config
+        .commit
+        .co_authors
+        .iter()
+        .any(|c| c.email.eq_ignore_ascii_case(email))
+    {
+        println!(
+            "\n{} {}",
+            style("Note:").yellow().bold(),
+            style(format!("{} is already in the pairing list", email)).yellow()
+        );
+        return Ok(());
+    }
+
+    config.commit.co_authors.push(CoAuthor {
+        name: name.to_string(),
+        email: email.to_string(),
+    });
+    save_config(&config)?;
+
+    println!(
+        "\n{} {}",
+        style("[✓]").green().bold(),
+        style(format!("Co-authored-by: {} <{}>", name, email)).green()
+    );
+
+    Ok(())
+}
+
+/// Remove a co-author by name or email
+pub(crate) async fn run_pair_remove_flow(who: &str) -> Result<()> {
+    flows_error::print_flow_header("[orca pair remove]");
+
+    let who = who.trim();
+    let mut config = load_user_config()?;
+    let before = config.commit.co_authors.len();
+    config
+        .commit
+        .co_authors
+        .retain(|c| !c.email.eq_ignore_ascii_case(who) && !c.name.eq_ignore_ascii_case(who));
+
+    if config.commit.co_authors.len() == before {
+        anyhow::bail!("No co-author matching '{}' in the pairing list", who);
+    }
+
+    save_config(&config)?;
+    println!(
+        "\n{} {}",
+        style("[✓]").green().bold(),
+        style(format!("Removed '{}' from the pairing list", who)).green()
+    );
+
+    Ok(())
+}
+
+/// Show the current pairing list
+pub(crate) async fn run_pair_list_flow() -> Result<()> {
+    flows_error::print_flow_header("[orca pair list]");
+
+    let config = load_config()?;
+    if config.commit.co_authors.is_empty() {
+        println!("\n{}", style("Not pairing with anyone").dim());
+        println!(
+            "\n{} {}",
+            style("Hint:").cyan().bold(),
+            style("orca pair add \"<NAME>\" <EMAIL>").cyan()
+        );
+        return Ok(());
+    }
+
+    println!("\n{}", style("Co-authors:").bold());
+    for c in &config.commit.co_authors {
+        println!("  {} {} <{}>", style("•").cyan(), style(&c.name).green(), c.email);
+    }
+
+    Ok(())
+}
+
+/// Clear the pairing list
+pub(crate) async fn run_pair_clear_flow() -> Result<()> {
+    flows_error::print_flow_header("[orca pair clear]");
+
+    let mut config = load_user_config()?;
+    config.commit.co_authors.clear();
+    save_config(&config)?;
+
+    println!(
+        "\n{} {}",
+        style("[✓]").green().bold(),
+        style("Pairing list cleared").green()
+    );
+
+    Ok(())
+}
@@ -1,24 +1,41 @@
 use crate::plan::{
     files_from_status_porcelain, normalize_plan_files, print_plan_human, CommitPlan, PlannedCommit,
 };
+use crate::commit_cache::PlanCacheKey;
+use crate::prompts::{PromptKind, PromptTemplates};
 use anyhow::{Context, Result};
 use console::style;
 use super::flows_spinner::spinner;
 use std::path::PathBuf;
 
+/// Resolve the style instruction and output language from the flag and config
+fn resolve_style_and_language(commit_style: Option<String>) -> Result<(Option<String>, Option<String>)> {
+    let config = crate::config::load_config()?;
+    let resolved_style = commit_style.or(config.git.commit_style).map(|s| {
+        // Allow preset names (e.g. "jira") in config as shorthand for the full instruction
+        crate::cli::CommitStylePreset::from_style(&s)
+            .map(|p| p.instruction().to_string())
+            .unwrap_or(s)
+    });
+    Ok((resolved_style, config.git.language))
+}
+
 pub(crate) async fn generate_plan(model: &str, status: &str, diff: &str, log: &str, commit_style: Option<String>) -> Result<CommitPlan> {
     let provider = crate::ai::create_provider().await?;
 
-    let config = crate::config::load_config()?;
-    let resolved_style = commit_style.or(config.git.commit_style);
-    let language = config.git.language;
-
-    let prompt = build_prompt(status, diff, log, resolved_style.as_deref(), language.as_deref());
+    let (resolved_style, language) = resolve_style_and_language(commit_style)?;
 
-    // Default system prompt. You can customize this or make it configurable.
-    let system_prompt = "You are a senior software engineer. Your task is to propose a commit plan.";
+    let templates = PromptTemplates::load(PromptKind::Commit);
+    let (system_prompt, prompt) = build_prompt(
+        &templates,
+        status,
+        diff,
+        log,
+        resolved_style.as_deref(),
+        language.as_deref(),
+    );
 
-    let resp_text = provider.generate_content(model, system_prompt, &prompt).await?;
+    let resp_text = provider.generate_content(model, &system_prompt, &prompt).await?;
     let json_text = extract_json(&resp_text).unwrap_or_else(|| resp_text.clone());
 
     if let Ok(plan) = serde_json::from_str::<CommitPlan>(&json_text) {
@@ -76,43 +93,25 @@ fn extract_json(input: &str) -> Option<String> {
     Some(s[start..=end].trim().to_string())
 }
 
-fn build_prompt(status: &str, diff: &str, log: &str, style: Option<&str>, language: Option<&str>) -> String {
-    let style_instruction = if let Some(s) = style {
-        format!("- Commit Message Style: {}\n", s)
-    } else {
-        String::new()
-    };
-
-    let lang_instruction = language.unwrap_or("Vietnamese or English based on the changes");
-
-    format!(
-        r#"Task: Propose a detailed commit plan for the current git working tree.
-    Rules:
-    - Output ONLY valid JSON. No markdown. No commentary.
-    - JSON schema: {{"commits":[{{"message":string,"files":[string],"commands":[string],"description":{{"summary":string,"changes":[string],"impact":{{"level":string,"explanation":string,"affected_areas":[string]}},"breaking_changes":[string]}}}}]}}
-    - Group files into logical commits by feature/responsibility.
-    - Commit messages should be concise, imperative, and conventional (e.g. feat:, fix:, refactor:, chore:).
-    {style_instruction}    - For each commit, provide a detailed description:
-      * summary: 2-3 sentences describing what changed and why (in {lang_instruction})
-      * changes: Bullet point list of the main changes
-      * impact: Assessment of impact level (low/medium/high) with explanation
-      * breaking_changes: List of breaking changes if any (empty array if none)
-    - Each file path must exist in git status output.
-    - For each commit, commands must contain EXACTLY 2 commands in this order:
-      1) git add -- <files...>
-      2) git commit -m "<message>"
-
-    Context:
-    GIT_STATUS_PORCELAIN:
-{status}
-
-    GIT_DIFF:
-{diff}
-
-    RECENT_GIT_LOG (for style):
-{log}
-"#,
-    )
+/// Render the commit plan prompts (system, user) from the resolved templates
+fn build_prompt(
+    templates: &PromptTemplates,
+    status: &str,
+    diff: &str,
+    log: &str,
+    style: Option<&str>,
+    language: Option<&str>,
+) -> (String, String) {
+    let style = style.unwrap_or("Match the style of the recent git log");
+    let language = language.unwrap_or("Vietnamese or English based on the changes");
+
+    templates.render(&[
+        ("status", status),
+        ("diff", diff),
+        ("log", log),
+        ("style", style),
+        ("language", language),
+    ])
 }
 
 pub(crate) async fn run_plan_flow(
@@ -128,7 +127,8 @@ pub(crate) async fn run_plan_flow(
     println!("{}", style("[orca plan]").bold().cyan());
 
     let status = crate::git::run_git(&["status", "--porcelain"])?;
-    let diff = crate::git::run_git(&["diff"])?;
+    // Same filtering as `orca commit` (binary/generated/ignored files as stats only)
+    let diff = crate::diff_optimizer::get_diff_for_ai(crate::diff_optimizer::DiffMode::Filtered)?;
     let log = match crate::git::run_git(&["log", "-n", "20", "--pretty=oneline"]) {
         Ok(v) => v,
         Err(e) => {
@@ -143,10 +143,22 @@ pub(crate) async fn run_plan_flow(
     }
 
     let changed_files = files_from_status_porcelain(&status);
+
+    let (resolved_style, language) = resolve_style_and_language(commit_style.clone())?;
+    // Editing a prompt template must not reuse plans generated from the old prompt
+    let prompt_version = PromptTemplates::load(PromptKind::Commit).cache_version();
+    let cache_key = PlanCacheKey {
+        diff: &diff,
+        status: &status,
+        model,
+        style: resolved_style.as_deref(),
+        language: language.as_deref(),
+        prompt_version: &prompt_version,
+    };
     
     // Try to load from cache if requested and not regenerating
     let mut plan = if use_cache && !regenerate {
-        if let Ok(Some(cached_plan)) = crate::commit_cache::load_cached_plan(&diff) {
+        if let Ok(Some(cached_plan)) = crate::commit_cache::load_cached_plan(&cache_key) {
             eprintln!(
                 "{} {}",
                 style("[✓]").green().bold(),
@@ -154,10 +166,10 @@ pub(crate) async fn run_plan_flow(
             );
             cached_plan
         } else {
-            generate_and_cache_plan(model, &status, &diff, &log, commit_style).await?
+            generate_and_cache_plan(&cache_key, &log, commit_style).await?
         }
     } else {
-        generate_and_cache_plan(model, &status, &diff, &log, commit_style).await?
+        generate_and_cache_plan(&cache_key, &log, commit_style).await?
     };
     
     normalize_plan_files(&mut plan, &changed_files);
@@ -186,12 +198,11 @@ pub(crate) async fn run_plan_flow(
 }
 
 async fn generate_and_cache_plan(
-    model: &str,
-    status: &str,
-    diff: &str,
+    cache_key: &PlanCacheKey<'_>,
     log: &str,
     commit_style: Option<String>,
 ) -> Result<CommitPlan> {
+    let (model, status, diff) = (cache_key.model, cache_key.status, cache_key.diff);
     let spinner_msg = if crate::config::get_provider() == "orca" {
         "Asking Orca Server to analyze changes and propose commit plan...".to_string()
     } else {
@@ -226,7 +237,7 @@ async fn generate_and_cache_plan(
     eprintln!("{} {}", style("[✓]").green().bold(), style("Plan received").green());
     
     // Cache the plan
-    if let Err(e) = crate::commit_cache::cache_plan(&plan, diff) {
+    if let Err(e) = crate::commit_cache::cache_plan(cache_key, &plan) {
         eprintln!(
             "{} {}",
             style("Warning:").yellow(),
@@ -241,15 +252,20 @@ async fn generate_and_cache_plan(
 mod tests {
     use super::*;
 
+    fn builtin_templates() -> PromptTemplates {
+        PromptTemplates::builtin(PromptKind::Commit)
+    }
+
     #[test]
     fn test_build_prompt_with_language() {
-        let prompt = build_prompt("status", "diff", "log", None, Some("French"));
+        let (_, prompt) = build_prompt(&builtin_templates(), "status", "diff", "log", None, Some("French"));
         assert!(prompt.contains("in French"));
     }
 
     #[test]
     fn test_build_prompt_without_language() {
-        let prompt = build_prompt("status", "diff", "log", None, None);
+        let (system, prompt) = build_prompt(&builtin_templates(), "status", "diff", "log", None, None);
+        assert!(system.contains("propose a commit plan"));
         assert!(prompt.contains("in Vietnamese or English based on the changes"));
     }
 }
@@ -0,0 +1,103 @@
+use crate::prompts::{global_prompts_dir, write_defaults, PromptKind, PromptTemplates, Template};
+use anyhow::Result;
+use console::style;
+use super::flows_error;
+
+/// Print the prompt templates in effect, with their source and placeholders
+pub(crate) async fn run_prompts_show_flow(name: Option<&str>) -> Result<()> {
+    flows_error::print_flow_header("[orca prompts show]");
+
+    let kinds: Vec<PromptKind> = match name {
+        Some(n) => match PromptKind::from_name(n) {
+            Some(kind) => vec![kind],
+            None => anyhow::bail!(
+                "Unknown prompt '{}'. Available: {}",
+                n,
+                PromptKind::ALL.map(|k| k.name()).join(", ")
+            ),
+        },
+        None => PromptKind::ALL.to_vec(),
+    };
+
+    for kind in kinds {
+        let templates = PromptTemplates::load(kind);
+        println!(
+            "\n{} {}",
+            style(kind.name()).bold().cyan(),
+            style(format!("(version {})", templates.cache_version())).dim()
+        );
+        println!(
+            "  {} {}",
+            style("Placeholders:").bold(),
+            kind.placeholders()
+                .iter()
+                .map(|p| format!("{{{}}}", p))
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+        print_template("System", &templates.system);
+        print_template("User", &templates.user);
+    }
+
+    println!(
+        "\n{} {}",
+        style("Hint:").cyan().bold(),
+        style("orca prompts init to copy the defaults into .orca/prompts/ and edit them").cyan()
+    );
+    Ok(())
+}
+
+fn print_template(label: &str, template: &Template) {
+    println!(
+        "\n  {} {}",
+        style(format!("{}:", label)).bold(),
+        style(&template.source).yellow()
+    );
+    println!("  {}", style("─".repeat(60)).dim());
+    for line in template.text.lines() {
+        println!("  {}", line);
+    }
+    println!("  {}", style("─".repeat(60)).dim());
+}
+
+/// Scaffold the built-in templates into the repository or global config directory
+pub(crate) async fn run_prompts_init_flow(global: bool, force: bool) -> Result<()> {
+    flows_error::print_flow_header("[orca prompts init]");
+
+    let dir = if global {
+        global_prompts_dir()?
+    } else {
+        crate::git::ensure_git_repo()?;
+        crate::git::get_repo_root()?.join(".orca").join("prompts")
+    };
+
+    let written = write_defaults(&dir, force)?;
+    if written.is_empty() {
+        println!(
+            "\n{} {}",
+            style("[!]").yellow().bold(),
+            style(format!("Templates already exist in {}", dir.display())).yellow()
+        );
+        println!(
+            "{} {}",
+            style("Hint:").cyan().bold(),
+            style("Use --force to overwrite them with the defaults").cyan()
+        );
+        return Ok(());
+    }
+
+    for path in &written {
+        println!("  {} {}", style("+").green(), path.display());
+    }
+    println!(
+        "\n{} {}",
+        style("[✓]").green().bold(),
+        style(format!("Wrote {} template(s) to {}", written.len(), dir.display())).green()
+    );
+    println!(
+        "{} {}",
+        style("Hint:").cyan().bold(),
+        style("Bump the prompt-version marker when you change a template's meaning").cyan()
+    );
+    Ok(())
+}
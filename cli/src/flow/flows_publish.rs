@@ -110,8 +110,8 @@ fn validate_base_and_commits(base: &str) -> Result<BaseValidation> {
 }
 
 fn suggest_branch_from_message_impl(msg: &str) -> String {
-    let msg = msg.trim();
-    let (typ, rest) = msg.split_once(':').unwrap_or(("feat", msg));
+    let (issue_key, msg) = crate::issue_key::split_key(msg.lines().next().unwrap_or(""));
+    let (typ, rest) = msg.split_once(':').unwrap_or(("feat", &msg));
     let typ = typ.trim();
     let mut slug = rest.trim().to_lowercase();
     slug = slug
@@ -134,7 +134,10 @@ fn suggest_branch_from_message_impl(msg: &str) -> String {
         slug = "work".to_string();
     }
 
-    format!("{typ}/{slug}")
+    match issue_key {
+        Some(key) => format!("{typ}/{}-{slug}", key.trim_start_matches('#')),
+        None => format!("{typ}/{slug}"),
+    }
 }
 
 pub(crate) fn suggest_branch_from_message(msg: &str) -> String {
@@ -428,6 +431,7 @@ async fn run_single_pr_workflow(
         style("[✓]").green().bold(),
         style("Branch pushed to origin").green()
     );
+    crate::commit_notes::sync_after_push("origin");
 
     if pr {
         create_single_pr(&target_branch, base, commits).await?;
@@ -777,6 +781,8 @@ async fn run_stack_pr_workflow(base: &str, pr: bool, commits: &[String], selecte
         eprintln!();
     }
 
+    crate::commit_notes::sync_after_push("origin");
+
     eprintln!(
         "{} {}",
         style("🎉 Stack completed:").green().bold(),
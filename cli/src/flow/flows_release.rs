@@ -1,4 +1,6 @@
+use crate::ai::models::{resolve_model, ModelTask};
 use crate::git::{ensure_git_repo, run_git};
+use crate::prompts::{PromptKind, PromptTemplates};
 use anyhow::{Context, Result};
 use console::style;
 use dialoguer::{Editor, Input};
@@ -142,7 +144,7 @@ pub(crate) async fn run_release_notes_flow(
     println!("\n{} {}", style("Range:").bold(), style(&range).cyan());
     
     // Get commit log
-    let log = run_git(&["log", &range, "--pretty=format:- %s (%h)"])?;
+    let log = release_log(&range)?;
     
     if log.trim().is_empty() {
         println!("\n{}", style("No commits in range").yellow());
@@ -163,17 +165,14 @@ pub(crate) async fn run_release_notes_flow(
             "HEAD".to_string()
         };
         
-        let log = run_git(&["log", &range, "--pretty=format:- %s (%h)"])?;
+        let log = release_log(&range)?;
         
-        let prompt = format!(
-            "Generate professional release notes from these commits. \
-            Group changes by category (Features, Bug Fixes, Improvements, etc.). \
-            Make it concise and user-friendly.\n\nCommits:\n{}",
-            log
-        );
+        let (system_prompt, prompt) = PromptTemplates::load(PromptKind::ReleaseNotes)
+            .render(&[("log", &log)]);
         
+        let model = resolve_model(ModelTask::ReleaseNotes, None);
         let provider = crate::ai::create_provider().await?;
-        match provider.generate_content("gemini-2.0-flash-exp", "", &prompt).await {
+        match provider.generate_content(&model, &system_prompt, &prompt).await {
             Ok(notes) => {
                 println!("\n{}", style("Release Notes:").bold().green());
                 println!("{}", style("═".repeat(60)).dim());
@@ -200,6 +199,28 @@ pub(crate) async fn run_release_notes_flow(
     Ok(())
 }
 
+/// One line per commit, followed by the summary from its orca note when there is one
+fn release_log(range: &str) -> Result<String> {
+    let raw = run_git(&["log", range, "--pretty=format:%H%x09%h%x09%s"])?;
+
+    let mut lines = Vec::new();
+    for entry in raw.lines() {
+        let mut parts = entry.splitn(3, '\t');
+        let (Some(hash), Some(short), Some(subject)) = (parts.next(), parts.next(), parts.next()) else {
+            continue;
+        };
+        lines.push(format!("- {} ({})", subject, short));
+        if let Some(desc) = crate::commit_notes::read_note(hash) {
+            let summary = desc.summary.trim();
+            if !summary.is_empty() {
+                lines.push(format!("  {}", summary));
+            }
+        }
+    }
+
+    Ok(lines.join("\n"))
+}
+
 /// Create GitHub release via gh CLI
 pub(crate) async fn run_release_create_flow(
     version: &str,
@@ -264,14 +285,12 @@ pub(crate) async fn run_release_create_flow(
         
         let log = run_git(&["log", &range, "--pretty=format:- %s (%h)"])?;
         
-        let prompt = format!(
-            "Generate professional release notes for version {}. \
-            Group changes by category. Make it concise.\n\nCommits:\n{}",
-            tag_name, log
-        );
+        let (system_prompt, prompt) = PromptTemplates::load(PromptKind::ReleaseCreate)
+            .render(&[("version", &tag_name), ("log", &log)]);
         
+        let model = resolve_model(ModelTask::ReleaseNotes, None);
         let provider = crate::ai::create_provider().await?;
-        provider.generate_content("gemini-2.0-flash-exp", "", &prompt).await?
+        provider.generate_content(&model, &system_prompt, &prompt).await?
     } else {
         // Prompt user to edit
         Editor::new()
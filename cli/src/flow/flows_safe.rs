@@ -1,70 +1,77 @@
-use crate::git::{ensure_git_repo, run_git};
+use super::flows_error::ExitCodeError;
+use crate::cli::ReportFormat;
+use crate::git::ensure_git_repo;
+use crate::large_files::{self, BlobOrigin, LargeBlob, LargeFileReport};
+use crate::preflight::{run_preflight, CheckStatus, PreflightCheck, PreflightPlan};
+use crate::secret_baseline::{write_baseline, Baseline, BASELINE_FILE};
+use crate::secret_report::{json_report, sarif_report, EXIT_FINDINGS};
+use crate::secret_rules::Confidence;
+use crate::secret_scan::{scan, scan_allowlisted, Finding, ScanReport, ScanTarget};
 use anyhow::Result;
 use console::style;
-use regex::Regex;
-use std::collections::HashSet;
+use serde::Serialize;
 
-// Secret patterns to detect
-const SECRET_PATTERNS: &[(&str, &str)] = &[
-    (r#"(?i)(api[_-]?key|apikey)\s*[:=]\s*['"]?[a-z0-9]{20,}['"]?"#, "API Key"),
-    (r#"(?i)(secret[_-]?key|secretkey)\s*[:=]\s*['"]?[a-z0-9]{20,}['"]?"#, "Secret Key"),
-    (r#"(?i)(access[_-]?token|accesstoken)\s*[:=]\s*['"]?[a-z0-9]{20,}['"]?"#, "Access Token"),
-    (r#"(?i)(auth[_-]?token|authtoken)\s*[:=]\s*['"]?[a-z0-9]{20,}['"]?"#, "Auth Token"),
-    (r#"(?i)(password|passwd|pwd)\s*[:=]\s*['"]?[^\s'";]{8,}['"]?"#, "Password"),
-    (r"-----BEGIN (RSA|DSA|EC|OPENSSH|PGP) PRIVATE KEY-----", "Private Key"),
-    (r"(?i)(aws_access_key_id|aws_secret_access_key)\s*[:=]", "AWS Credentials"),
-    (r#"(?i)(github|gitlab)_token\s*[:=]\s*['"]?[a-z0-9]{20,}['"]?"#, "GitHub/GitLab Token"),
-    (r"(?i)Bearer\s+[a-zA-Z0-9\-._~+/]+=*", "Bearer Token"),
-    (r"sk-[a-zA-Z0-9]{32,}", "OpenAI API Key"),
-];
-
-// Risky file patterns
-const RISKY_FILES: &[&str] = &[
-    ".env",
-    ".env.local",
-    ".env.development",
-    ".env.production",
-    ".env.test",
-    "*.pem",
-    "*.key",
-    "*.p12",
-    "*.pfx",
-    "id_rsa",
-    "id_dsa",
-    "id_ecdsa",
-    "id_ed25519",
-    "*.kdb",
-    "*.kdbx",
-    "credentials.json",
-    "service-account.json",
-];
-
-#[derive(Debug)]
-struct SecretMatch {
-    file: String,
-    line_number: usize,
-    pattern_name: String,
-    context: String,
+/// Error for a scan or preflight that ran to completion and found problems
+fn findings_error(message: &str) -> anyhow::Error {
+    ExitCodeError::wrap(EXIT_FINDINGS, anyhow::anyhow!(message.to_string()))
 }
 
-/// Scan staged files for secrets
-pub(crate) async fn run_safe_scan_flow(all: bool) -> Result<()> {
+/// Scan staged changes (or all tracked files, or a commit range) for secrets.
+/// Findings below `min_confidence` are listed but do not fail the scan.
+/// With `update_baseline`, current findings are recorded as accepted instead.
+pub(crate) async fn run_safe_scan_flow(
+    target: ScanTarget,
+    min_confidence: Confidence,
+    update_baseline: bool,
+    format: ReportFormat,
+) -> Result<()> {
     ensure_git_repo()?;
     
-    println!("{}", style("[orca safe scan]").bold().cyan());
-    
-    // Get files to scan
-    let files_output = if all {
-        run_git(&["ls-files"])?
-    } else {
-        run_git(&["diff", "--cached", "--name-only"])?
-    };
+    if format == ReportFormat::Text {
+        println!("{}", style("[orca safe scan]").bold().cyan());
+    }
+
+    check_revision(&target)?;
     
-    let files: Vec<_> = files_output.lines()
-        .filter(|l| !l.trim().is_empty())
-        .collect();
+    if update_baseline {
+        let report = scan_allowlisted(&target)?;
+        let baseline = Baseline::from_findings(&report.findings);
+        write_baseline(&baseline)?;
+        println!(
+            "\n{} Recorded {} finding(s) in {}",
+            style("[✓]").green().bold(),
+            baseline.findings.len(),
+            BASELINE_FILE
+        );
+        println!(
+            "{} Commit {} so later scans only report new findings",
+            style("Hint:").yellow().bold(),
+            style(BASELINE_FILE).cyan()
+        );
+        return Ok(());
+    }
+
+    let report = scan(&target)?;
+
+    match format {
+        ReportFormat::Text => {}
+        ReportFormat::Json => {
+            let json = json_report(&report, &target, min_confidence);
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+        ReportFormat::Sarif => {
+            let sarif = sarif_report(&report, min_confidence, None);
+            println!("{}", serde_json::to_string_pretty(&sarif)?);
+        }
+    }
+    if format != ReportFormat::Text {
+        if report.issue_count(min_confidence) > 0 {
+            return Err(findings_error("Security issues detected"));
+        }
+        return Ok(());
+    }
     
-    if files.is_empty() {
+    if report.files.is_empty() {
         println!("\n{}", style("No files to scan").dim());
         return Ok(());
     }
@@ -72,71 +79,44 @@ pub(crate) async fn run_safe_scan_flow(all: bool) -> Result<()> {
     println!(
         "\n{} {}",
         style("Scanning:").bold(),
-        style(format!("{} files", files.len())).cyan()
+        style(format!("{} files ({})", report.files.len(), target.describe())).cyan()
     );
-    
-    let mut issues_found = vec![];
-    let mut risky_files_found = vec![];
-    
-    // Compile regex patterns
-    let patterns: Vec<_> = SECRET_PATTERNS.iter()
-        .filter_map(|(pattern, name)| {
-            Regex::new(pattern).ok().map(|r| (r, *name))
-        })
-        .collect();
-    
-    // Check for risky file names
-    for file in &files {
-        let file_name = file.to_lowercase();
-        for risky_pattern in RISKY_FILES {
-            let pattern_lower = risky_pattern.to_lowercase();
-            if pattern_lower.contains('*') {
-                // Simple wildcard matching
-                let pattern_parts: Vec<&str> = pattern_lower.split('*').collect();
-                if pattern_parts.len() == 2 {
-                    if file_name.starts_with(pattern_parts[0]) && file_name.ends_with(pattern_parts[1]) {
-                        risky_files_found.push(file.to_string());
-                        break;
-                    }
-                }
-            } else if file_name.contains(&pattern_lower) || file_name.ends_with(&pattern_lower) {
-                risky_files_found.push(file.to_string());
-                break;
-            }
-        }
+    if report.rule_sources != ["built-in"] {
+        println!(
+            "{} {}",
+            style("Rules:").bold(),
+            style(report.rule_sources.join(" + ")).cyan()
+        );
+    }
+    if report.allowlisted > 0 || report.baselined > 0 {
+        println!(
+            "{}",
+            style(format!(
+                "Skipped {} allowlisted and {} baselined finding(s)",
+                report.allowlisted, report.baselined
+            ))
+            .dim()
+        );
     }
     
-    // Scan file contents
-    for file in &files {
-        // Skip binary files (simple check)
-        if file.ends_with(".png") || file.ends_with(".jpg") || file.ends_with(".gif") 
-            || file.ends_with(".pdf") || file.ends_with(".zip") || file.ends_with(".exe") {
-            continue;
-        }
-        
-        // Get file content
-        let content = match std::fs::read_to_string(file) {
-            Ok(c) => c,
-            Err(_) => continue, // Skip if can't read (likely binary)
-        };
-        
-        // Check each pattern
-        for (regex, pattern_name) in &patterns {
-            for (line_num, line) in content.lines().enumerate() {
-                if regex.is_match(line) {
-                    issues_found.push(SecretMatch {
-                        file: file.to_string(),
-                        line_number: line_num + 1,
-                        pattern_name: pattern_name.to_string(),
-                        context: line.trim().to_string(),
-                    });
-                }
-            }
+    let (blocking, advisory): (Vec<&Finding>, Vec<&Finding>) = report
+        .findings
+        .iter()
+        .partition(|f| f.confidence >= min_confidence);
+    
+    if !advisory.is_empty() {
+        println!(
+            "\n{} Below {} confidence (not blocking):",
+            style("[?]").dim().bold(),
+            min_confidence.as_str()
+        );
+        for issue in &advisory {
+            print_finding(issue, false);
         }
     }
     
     // Report findings
-    if risky_files_found.is_empty() && issues_found.is_empty() {
+    if report.issue_count(min_confidence) == 0 {
         println!(
             "\n{} {}",
             style("[✓]").green().bold(),
@@ -146,131 +126,344 @@ pub(crate) async fn run_safe_scan_flow(all: bool) -> Result<()> {
     }
     
     // Show risky files
-    if !risky_files_found.is_empty() {
+    if !report.risky_files.is_empty() {
         println!(
             "\n{} Risky files detected:",
             style("[!]").yellow().bold()
         );
-        for file in &risky_files_found {
+        for file in &report.risky_files {
             println!("  {} {}", style("•").yellow(), style(file).yellow());
         }
     }
     
     // Show secret matches
-    if !issues_found.is_empty() {
+    if !blocking.is_empty() {
         println!(
             "\n{} Potential secrets detected:",
             style("[!]").red().bold()
         );
         
-        for issue in &issues_found {
-            println!(
-                "  {} {} (Line {}) - {}",
-                style("•").red(),
-                style(&issue.file).red(),
-                style(issue.line_number).dim(),
-                style(&issue.pattern_name).yellow()
-            );
-            println!("    {}", style(&issue.context).dim());
+        for issue in &blocking {
+            print_finding(issue, true);
         }
     }
     
+    if matches!(target, ScanTarget::History { .. }) {
+        print_history_remediation(&report);
+        return Err(findings_error(
+            "Secrets found in git history. Rotate them before anything else.",
+        ));
+    }
+    
     // Provide guidance
     println!("\n{}", style("Recommendations:").bold().cyan());
-    if !risky_files_found.is_empty() {
+    if !report.risky_files.is_empty() {
         println!("  1. Add sensitive files to .gitignore");
         println!("  2. Remove them from staging:");
         println!("     {}", style("git reset HEAD <file>").cyan());
     }
-    if !issues_found.is_empty() {
+    if !blocking.is_empty() {
         println!("  3. Never commit secrets to version control");
         println!("  4. Use environment variables or secret management");
         println!("  5. If already committed, rotate the secrets immediately");
+        println!(
+            "  6. For false positives, add {} to the line or allow the path/rule under [secrets] in .orca.toml",
+            style(format!("# {}", crate::secret_scan::INLINE_ALLOW)).cyan()
+        );
     }
     
-    anyhow::bail!("Security issues detected. Aborting to prevent secret leaks.");
+    Err(findings_error("Security issues detected. Aborting to prevent secret leaks."))
+}
+
+/// A leading dash would be read as a git option
+fn check_revision(target: &ScanTarget) -> Result<()> {
+    let revision = match target {
+        ScanTarget::Range(range) => Some(range),
+        ScanTarget::History { since } => since.as_ref(),
+        ScanTarget::Staged | ScanTarget::All => None,
+    };
+    if let Some(rev) = revision
+        && rev.starts_with('-')
+    {
+        anyhow::bail!("Invalid revision '{}'", rev);
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct LargeFilesJson<'a> {
+    schema_version: u32,
+    target: String,
+    passed: bool,
+    #[serde(flatten)]
+    report: &'a LargeFileReport,
+    fixes: Vec<String>,
+}
+
+/// Flag blobs over the size limit and LFS-tracked files stored as regular blobs
+pub(crate) async fn run_safe_large_files_flow(
+    target: ScanTarget,
+    max_size_kb: Option<u64>,
+    format: ReportFormat,
+) -> Result<()> {
+    ensure_git_repo()?;
+    check_revision(&target)?;
+    if format == ReportFormat::Sarif {
+        anyhow::bail!("SARIF output is only available for secret scans; use --format json");
+    }
+
+    let limit = match max_size_kb {
+        Some(kb) => kb * 1024,
+        None => large_files::limit_bytes(&crate::config::load_config()?.large_files),
+    };
+    let report = large_files::scan_large_files(&target, limit)?;
+    let passed = report.issue_count() == 0;
+    let fixes = if passed { Vec::new() } else { large_files::remediation(&report) };
+
+    if format == ReportFormat::Json {
+        let json = LargeFilesJson {
+            schema_version: 1,
+            target: target.describe(),
+            passed,
+            report: &report,
+            fixes,
+        };
+        println!("{}", serde_json::to_string_pretty(&json)?);
+        if !passed {
+            return Err(findings_error("Large files detected"));
+        }
+        return Ok(());
+    }
+
+    println!("{}", style("[orca safe scan --large-files]").bold().cyan());
+    println!(
+        "\n{} {}",
+        style("Checking:").bold(),
+        style(format!(
+            "{} blob(s) ({}), limit {}",
+            report.blobs_checked,
+            target.describe(),
+            large_files::human_size(limit)
+        ))
+        .cyan()
+    );
+
+    if passed {
+        println!(
+            "\n{} {}",
+            style("[✓]").green().bold(),
+            style("No large files or missing LFS pointers").green()
+        );
+        return Ok(());
+    }
+
+    if !report.oversized.is_empty() {
+        println!(
+            "\n{} Files over {}:",
+            style("[!]").red().bold(),
+            large_files::human_size(limit)
+        );
+        for blob in &report.oversized {
+            print_large_blob(blob);
+        }
+    }
+    if !report.not_in_lfs.is_empty() {
+        println!(
+            "\n{} Tracked by Git LFS but stored as regular blobs:",
+            style("[!]").red().bold()
+        );
+        for blob in &report.not_in_lfs {
+            print_large_blob(blob);
+        }
+    }
+
+    println!("\n{}", style("Move them to Git LFS:").bold().cyan());
+    for fix in &fixes {
+        println!("  {}", style(fix).cyan());
+    }
+    if report.oversized.iter().chain(&report.not_in_lfs).any(|b| b.origin == BlobOrigin::Commit) {
+        println!(
+            "\n{} `git lfs migrate import` rewrites commits; run it before pushing, or coordinate with your team for pushed history",
+            style("Hint:").yellow().bold()
+        );
+    }
+
+    Err(findings_error("Large files detected"))
+}
+
+fn print_large_blob(blob: &LargeBlob) {
+    let origin = match blob.origin {
+        BlobOrigin::Index => "staged",
+        BlobOrigin::Commit => "committed",
+    };
+    println!(
+        "  {} {} {}",
+        style("•").red(),
+        style(&blob.path).bold(),
+        style(format!("({}, {} {})", large_files::human_size(blob.size), origin, &blob.oid[..blob.oid.len().min(10)])).dim()
+    );
 }
 
-/// Run preflight checks before push/tag/release
+fn print_finding(issue: &Finding, blocking: bool) {
+    let commit = issue
+        .commit
+        .as_ref()
+        .map(|c| format!(" @ {}", c.short_hash()))
+        .unwrap_or_default();
+    let file = if blocking {
+        style(&issue.file).red()
+    } else {
+        style(&issue.file).dim()
+    };
+    println!(
+        "  {} {} (Line {}){} - {} {}",
+        if blocking { style("•").red() } else { style("•").dim() },
+        file,
+        style(issue.line_number).dim(),
+        style(commit).dim(),
+        style(&issue.pattern_name).yellow(),
+        style(format!("[{}, {}]", issue.confidence.as_str(), issue.rule_id)).dim()
+    );
+    if let Some(c) = &issue.commit {
+        println!("    {} {}", style(&c.author).dim(), style(&c.date).dim());
+    }
+    println!("    {}", style(&issue.context).dim());
+}
+
+/// Secrets in history stay readable in every clone until rotated and rewritten out
+fn print_history_remediation(report: &ScanReport) {
+    let mut files: Vec<&str> = report
+        .findings
+        .iter()
+        .map(|f| f.file.as_str())
+        .chain(report.risky_files.iter().map(String::as_str))
+        .collect();
+    files.sort_unstable();
+    files.dedup();
+
+    println!("\n{}", style("Remediation:").bold().cyan());
+    println!("  1. Rotate or revoke every secret listed above. Removing it from git is not enough:");
+    println!("     anyone with a clone or fork already has it.");
+    println!("  2. Rewrite history to drop the secrets (requires git-filter-repo):");
+    println!(
+        "     {}",
+        style("git filter-repo --replace-text secrets.txt   # one 'secret==>REDACTED' per line").cyan()
+    );
+    if !files.is_empty() {
+        let paths: Vec<String> = files.iter().map(|f| format!("--path '{}'", f)).collect();
+        println!("     or remove whole files:");
+        println!(
+            "     {}",
+            style(format!("git filter-repo --invert-paths {}", paths.join(" "))).cyan()
+        );
+    }
+    println!("  3. Force-push the rewritten branches and tags, then ask collaborators to re-clone:");
+    println!("     {}", style("git push --force --all && git push --force --tags").cyan());
+    println!("  4. Ask your hosting provider to purge cached views and pull request refs if needed");
+}
+
+#[derive(Serialize)]
+struct PreflightJson<'a> {
+    schema_version: u32,
+    operation: &'a str,
+    /// Config file the profile came from; absent for the built-in default
+    profile: Option<String>,
+    passed: bool,
+    checks: &'a [PreflightCheck],
+    secret_scan: Option<crate::secret_report::JsonReport<'a>>,
+}
+
+/// Run the preflight profile for `operation` before push/tag/release
 pub(crate) async fn run_safe_preflight_flow(
     operation: &str,
     protection: Option<&str>,
+    format: ReportFormat,
 ) -> Result<()> {
     ensure_git_repo()?;
-    
-    println!("{}", style(format!("[orca safe preflight: {}]", operation)).bold().cyan());
-    
-    let mut checks_passed = true;
-    let mut warnings = vec![];
-    
-    // Check 1: Scan for secrets in staged files
-    println!("\n{}", style("1. Scanning for secrets...").dim());
-    match run_safe_scan_silently(false).await {
-        Ok(issues) => {
-            if issues > 0 {
-                println!(
-                    "  {} {} potential secret(s) found",
-                    style("[!]").red().bold(),
-                    issues
-                );
-                checks_passed = false;
-            } else {
-                println!("  {} No secrets detected", style("[✓]").green());
-            }
+    let repo_root = crate::git::get_repo_root()?;
+    let plan = PreflightPlan::load(operation, protection)?;
+
+    let spinner = (format == ReportFormat::Text && plan.command_count() > 0)
+        .then(|| super::flows_spinner::spinner("Running preflight checks..."));
+    let run = run_preflight(&plan, &repo_root);
+    if let Some(spinner) = spinner {
+        spinner.finish_and_clear();
+    }
+    let run = run?;
+
+    let passed = run.passed();
+    let target = ScanTarget::Staged;
+    match format {
+        ReportFormat::Text => print_preflight(&plan, &run.checks, passed),
+        ReportFormat::Json => {
+            let json = PreflightJson {
+                schema_version: 1,
+                operation,
+                profile: plan.source.as_ref().map(|p| p.display().to_string()),
+                passed,
+                checks: &run.checks,
+                secret_scan: run
+                    .secret_scan
+                    .as_ref()
+                    .and_then(|scanned| scanned.as_ref().ok())
+                    .map(|report| json_report(report, &target, Confidence::Medium)),
+            };
+            println!("{}", serde_json::to_string_pretty(&json)?);
         }
-        Err(e) => {
-            warnings.push(format!("Secret scan failed: {}", e));
+        ReportFormat::Sarif => {
+            // SARIF carries code locations, so only the secret scan is reported
+            let empty = ScanReport::default();
+            let (report, error) = match &run.secret_scan {
+                Some(Ok(report)) => (report, None),
+                Some(Err(e)) => (&empty, Some(e.to_string())),
+                None => (&empty, None),
+            };
+            let sarif = sarif_report(report, Confidence::Medium, error.as_deref());
+            println!("{}", serde_json::to_string_pretty(&sarif)?);
         }
     }
     
-    // Check 2: Verify branch protection
-    if let Some(protected_branch) = protection {
-        println!("\n{}", style("2. Checking branch protection...").dim());
-        let current = crate::git::current_branch()?;
-        if current == protected_branch {
-            println!(
-                "  {} Pushing directly to '{}'",
-                style("[!]").yellow().bold(),
-                protected_branch
-            );
-            warnings.push(format!(
-                "Consider using a feature branch and PR instead of pushing to '{}'",
-                protected_branch
-            ));
-        } else {
-            println!("  {} Not pushing to protected branch", style("[✓]").green());
-        }
+    if !passed {
+        return Err(findings_error("Preflight checks failed"));
+    }
+    Ok(())
+}
+
+fn print_preflight(plan: &PreflightPlan, checks: &[PreflightCheck], passed: bool) {
+    println!("{}", style(format!("[orca safe preflight: {}]", plan.operation)).bold().cyan());
+    if let Some(source) = &plan.source {
+        println!("{}", style(format!("Profile: {}", source.display())).dim());
     }
     
-    // Check 3: Verify upstream configured (for push)
-    if operation == "push" {
-        println!("\n{}", style("3. Checking upstream configuration...").dim());
-        match crate::git::upstream_ref()? {
-            Some(upstream) => {
-                println!("  {} Upstream: {}", style("[✓]").green(), upstream);
-            }
-            None => {
-                println!(
-                    "  {} No upstream configured",
-                    style("[!]").yellow().bold()
-                );
-                warnings.push("Branch has no upstream. Will set with -u flag.".to_string());
+    for (i, check) in checks.iter().enumerate() {
+        println!("\n{}", style(format!("{}. {}...", i + 1, check.title)).dim());
+        match check.status {
+            CheckStatus::Pass => println!("  {} {}", style("[✓]").green(), check.message),
+            CheckStatus::Warn => println!("  {} {}", style("[!]").yellow().bold(), check.message),
+            CheckStatus::Fail => println!("  {} {}", style("[!]").red().bold(), check.message),
+        }
+        if let Some(output) = &check.output {
+            for line in output.lines() {
+                println!("      {}", style(line).dim());
             }
         }
     }
     
     // Summary
     println!("\n{}", style("═".repeat(60)).dim());
+    print_summary_table(checks);
+    println!();
     
-    if !checks_passed {
+    if !passed {
         println!(
             "{} {}",
             style("[✗]").red().bold(),
             style("Preflight checks FAILED").red().bold()
         );
-        anyhow::bail!("Preflight checks failed");
+        return;
     }
     
+    let warnings: Vec<&String> = checks.iter().filter_map(|c| c.warning.as_ref()).collect();
     if !warnings.is_empty() {
         println!(
             "{} {} with warnings",
@@ -287,74 +480,33 @@ pub(crate) async fn run_safe_preflight_flow(
             style("All preflight checks passed").green()
         );
     }
-    
-    Ok(())
 }
 
-/// Run scan silently and return issue count
-async fn run_safe_scan_silently(all: bool) -> Result<usize> {
-    let files_output = if all {
-        run_git(&["ls-files"])?
-    } else {
-        run_git(&["diff", "--cached", "--name-only"])?
-    };
-    
-    let files: Vec<_> = files_output.lines()
-        .filter(|l| !l.trim().is_empty())
-        .collect();
-    
-    if files.is_empty() {
-        return Ok(0);
-    }
-    
-    let mut issue_count = 0;
-    let mut risky_files = HashSet::new();
-    
-    // Check risky file names
-    for file in &files {
-        let file_name = file.to_lowercase();
-        for risky_pattern in RISKY_FILES {
-            let pattern_lower = risky_pattern.to_lowercase();
-            if pattern_lower.contains('*') {
-                let pattern_parts: Vec<&str> = pattern_lower.split('*').collect();
-                if pattern_parts.len() == 2 {
-                    if file_name.starts_with(pattern_parts[0]) && file_name.ends_with(pattern_parts[1]) {
-                        risky_files.insert(file.to_string());
-                        break;
-                    }
-                }
-            } else if file_name.contains(&pattern_lower) || file_name.ends_with(&pattern_lower) {
-                risky_files.insert(file.to_string());
-                break;
-            }
-        }
-    }
-    
-    issue_count += risky_files.len();
-    
-    // Compile patterns
-    let patterns: Vec<_> = SECRET_PATTERNS.iter()
-        .filter_map(|(pattern, _)| Regex::new(pattern).ok())
-        .collect();
-    
-    // Scan contents
-    for file in &files {
-        if file.ends_with(".png") || file.ends_with(".jpg") || file.ends_with(".gif") 
-            || file.ends_with(".pdf") || file.ends_with(".zip") || file.ends_with(".exe") {
-            continue;
-        }
-        
-        if let Ok(content) = std::fs::read_to_string(file) {
-            for regex in &patterns {
-                for line in content.lines() {
-                    if regex.is_match(line) {
-                        issue_count += 1;
-                        break;
-                    }
-                }
-            }
-        }
+/// One row per check: name, status, duration and the one-line result
+fn print_summary_table(checks: &[PreflightCheck]) {
+    let width = checks.iter().map(|c| c.name.chars().count()).max().unwrap_or(0).max(5);
+    println!(
+        "{}",
+        style(format!("{:<width$}  {:<6}  {:>7}  {}", "Check", "Status", "Time", "Details")).bold()
+    );
+    for check in checks {
+        // Pad before styling; escape codes would throw off the alignment
+        let status = format!("{:<6}", match check.status {
+            CheckStatus::Pass => "pass",
+            CheckStatus::Warn => "warn",
+            CheckStatus::Fail => "FAIL",
+        });
+        let status = match check.status {
+            CheckStatus::Pass => style(status).green(),
+            CheckStatus::Warn => style(status).yellow().bold(),
+            CheckStatus::Fail => style(status).red().bold(),
+        };
+        println!(
+            "{:<width$}  {}  {:>7}  {}",
+            check.name,
+            status,
+            format!("{:.1}s", check.duration_ms as f64 / 1000.0),
+            check.message
+        );
     }
-    
-    Ok(issue_count)
 }
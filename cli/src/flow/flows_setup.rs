@@ -19,7 +19,7 @@ pub(crate) async fn run_setup_flow(
     let use_global = !local;
 
     // Load existing config or default
-    let mut config = crate::config::load_config().unwrap_or_default();
+    let mut config = crate::config::load_user_config().unwrap_or_default();
     let mut config_changed = false;
 
     // 1. Handle Provider Switch
@@ -1,5 +1,9 @@
 pub(crate) use indicatif::ProgressBar;
 use indicatif::{ProgressDrawTarget, ProgressStyle};
+use std::sync::Mutex;
+
+/// Most recent spinner, so code without a handle can print or prompt around it
+static ACTIVE: Mutex<Option<ProgressBar>> = Mutex::new(None);
 
 pub(crate) fn spinner(msg: &str) -> ProgressBar {
     let pb = ProgressBar::new_spinner();
@@ -11,5 +15,21 @@ pub(crate) fn spinner(msg: &str) -> ProgressBar {
     );
     pb.enable_steady_tick(std::time::Duration::from_millis(120));
     pb.set_message(msg.to_string());
+    if let Ok(mut active) = ACTIVE.lock() {
+        *active = Some(pb.clone());
+    }
     pb
 }
+
+/// Run `f` with the active spinner (if still ticking) hidden, e.g. to print or ask for input
+pub(crate) fn suspend_spinner<R>(f: impl FnOnce() -> R) -> R {
+    let active = ACTIVE
+        .lock()
+        .ok()
+        .and_then(|a| a.clone())
+        .filter(|pb| !pb.is_finished());
+    match active {
+        Some(pb) => pb.suspend(f),
+        None => f(),
+    }
+}
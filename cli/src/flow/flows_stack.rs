@@ -312,11 +312,12 @@ pub(crate) async fn run_stack_publish_flow(pr: bool, yes: bool, _yes_pr: bool) -
             style("Creating PRs for stacked branches...").yellow()
         );
         
+        let default_base = crate::config::default_base_branch();
         for branch_name in &publish_order {
             let stack_info = metadata.stacks.get(branch_name);
             let base = stack_info
                 .and_then(|info| if info.parent.is_empty() { None } else { Some(info.parent.as_str()) })
-                .unwrap_or("main");
+                .unwrap_or(&default_base);
             
             println!("\n{} {}", style("Creating PR for:").bold(), style(branch_name).cyan());
             println!("  {} {}", style("Base:").bold(), style(base).dim());
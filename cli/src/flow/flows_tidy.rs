@@ -1,3 +1,4 @@
+use crate::branch_policy::{enforce, enforce_current, ProtectedOperation};
 use crate::git::{current_branch, ensure_git_repo, is_working_tree_clean, run_git};
 use anyhow::{Context, Result};
 use console::style;
@@ -26,6 +27,7 @@ pub(crate) async fn run_tidy_rebase_flow(
     
     let branch = current_branch()?;
     println!("\n{} {}", style("Current branch:").bold(), style(&branch).green());
+    enforce(&branch, ProtectedOperation::Rewrite)?;
     
     // Determine base
     let base = if let Some(b) = onto {
@@ -33,7 +35,7 @@ pub(crate) async fn run_tidy_rebase_flow(
     } else {
         Input::<String>::new()
             .with_prompt("Rebase onto which branch?")
-            .default("main".to_string())
+            .default(crate::config::default_base_branch())
             .interact_text()
             .context("Failed to read base branch")?
     };
@@ -87,9 +89,11 @@ pub(crate) async fn run_tidy_squash_flow(base: Option<&str>, yes: bool) -> Resul
     
     let branch = current_branch()?;
     println!("\n{} {}", style("Current branch:").bold(), style(&branch).green());
+    enforce(&branch, ProtectedOperation::Rewrite)?;
     
     // Determine base
-    let base_branch = base.unwrap_or("main");
+    let default_base = crate::config::default_base_branch();
+    let base_branch = base.unwrap_or(&default_base);
     println!("{} {}", style("Base branch:").bold(), style(base_branch).cyan());
     
     // Count commits
@@ -158,6 +162,8 @@ pub(crate) async fn run_tidy_fixup_flow(commit: &str) -> Result<()> {
         style(summary.trim()).yellow()
     );
     
+    enforce_current(ProtectedOperation::Commit)?;
+
     // Create fixup commit
     println!("\n{}", style("Creating fixup commit...").dim());
     run_git(&["commit", "--fixup", commit])?;
@@ -181,6 +187,7 @@ pub(crate) async fn run_tidy_amend_flow(no_edit: bool, yes: bool) -> Result<()>
     ensure_git_repo()?;
 
     flows_error::print_flow_header("[orca tidy amend]");
+    enforce_current(ProtectedOperation::Rewrite)?;
     
     // Get last commit
     let last_commit = run_git(&["log", "-1", "--pretty=%H %s"])?;
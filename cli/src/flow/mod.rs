@@ -16,6 +16,11 @@ pub(crate) mod flows_conflict;
 pub(crate) mod flows_release;
 pub(crate) mod flows_stack;
 pub(crate) mod flows_safe;
+pub(crate) mod flows_hooks;
+pub(crate) mod flows_pair;
+pub(crate) mod flows_notes;
+pub(crate) mod flows_cache;
+pub(crate) mod flows_prompts;
 pub(crate) mod pr_template;
 pub(crate) mod pr_workflow;
 pub(crate) mod update;
@@ -281,7 +281,7 @@ fn populate_template_with_refs(template: &str, commits: &[CommitRef]) -> String
     // Generate smart summary
     let summary = generate_smart_summary(&messages, &categories);
 
-    let cached_details = build_cached_commit_details(commits);
+    let cached_details = build_commit_details(commits);
     let summary_with_details = if cached_details.trim().is_empty() {
         summary.clone()
     } else {
@@ -320,28 +320,35 @@ fn populate_template_with_refs(template: &str, commits: &[CommitRef]) -> String
     result
 }
 
-fn build_cached_commit_details(commits: &[CommitRef]) -> String {
-    let plan = match crate::commit_cache::load_latest_cached_plan().ok().flatten() {
-        Some(p) => p,
-        None => return String::new(),
-    };
+fn build_commit_details(commits: &[CommitRef]) -> String {
+    // Notes are the durable source; the plan cache only covers commits that have no note yet
+    let cached_plan = crate::commit_cache::load_latest_cached_plan().ok().flatten();
 
     let mut lines: Vec<String> = Vec::new();
     for commit_msg in commits {
-        let planned = commit_msg
+        let from_note = commit_msg
             .hash
             .as_deref()
-            .and_then(|h| plan.commits.iter().find(|c| c.hash.as_deref() == Some(h)))
-            .or_else(|| {
-                plan.commits
-                    .iter()
-                    .find(|c| c.message.trim() == commit_msg.message.trim())
-            });
-        let Some(planned) = planned else {
-            continue;
-        };
-        let Some(desc) = planned.description.as_ref() else {
-            continue;
+            .and_then(crate::commit_notes::read_note);
+        let desc = match from_note {
+            Some(desc) => desc,
+            None => {
+                let planned = cached_plan.as_ref().and_then(|plan| {
+                    commit_msg
+                        .hash
+                        .as_deref()
+                        .and_then(|h| plan.commits.iter().find(|c| c.hash.as_deref() == Some(h)))
+                        .or_else(|| {
+                            plan.commits
+                                .iter()
+                                .find(|c| c.message.trim() == commit_msg.message.trim())
+                        })
+                });
+                match planned.and_then(|p| p.description.clone()) {
+                    Some(desc) => desc,
+                    None => continue,
+                }
+            }
         };
 
         if lines.is_empty() {
@@ -351,10 +358,10 @@ fn build_cached_commit_details(commits: &[CommitRef]) -> String {
 
         lines.push(format!(
             "<details>\n<summary><code>{}</code></summary>",
-            planned.message.trim()
+            commit_msg.message.trim()
         ));
         lines.push(String::new());
-        lines.extend(format_commit_description_for_pr(desc));
+        lines.extend(format_commit_description_for_pr(&desc));
         lines.push(String::new());
         lines.push("</details>".to_string());
         lines.push(String::new());
@@ -429,6 +436,27 @@ fn format_commit_description_for_pr(desc: &CommitDescription) -> Vec<String> {
 
 /// Generate smart PR title from multiple commits
 pub fn generate_pr_title(commits: &[String]) -> String {
+    // Summarize the bare subjects, then put the issue key back once
+    let mut found_key = None;
+    let subjects: Vec<String> = commits
+        .iter()
+        .map(|c| {
+            let (key, subject) = crate::issue_key::split_key(c);
+            if found_key.is_none() {
+                found_key = key;
+            }
+            subject
+        })
+        .collect();
+
+    let title = summarize_subjects(&subjects);
+    match crate::issue_key::pr_title_key(found_key) {
+        Some(key) => key.inject(&title),
+        None => title,
+    }
+}
+
+fn summarize_subjects(commits: &[String]) -> String {
     if commits.is_empty() {
         return "Update".to_string();
     }
@@ -1,3 +1,4 @@
+use crate::branch_policy::ProtectedOperation;
 use anyhow::{Context, Result};
 use std::collections::HashSet;
 use std::path::PathBuf;
@@ -82,6 +83,12 @@ pub(crate) fn get_repo_root() -> Result<PathBuf> {
     Ok(PathBuf::from(path_str))
 }
 
+/// Absolute `.git` directory shared by every worktree of the repository
+pub(crate) fn git_common_dir() -> Result<PathBuf> {
+    let out = run_git(&["rev-parse", "--path-format=absolute", "--git-common-dir"])?;
+    Ok(PathBuf::from(out.trim()))
+}
+
 pub(crate) fn run_git(args: &[&str]) -> Result<String> {
     let repo_root = get_repo_root()?;
     let out = Command::new("git")
@@ -98,6 +105,12 @@ pub(crate) fn run_git(args: &[&str]) -> Result<String> {
 }
 
 pub(crate) fn run_git_with_input(args: &[&str], input: &str) -> Result<String> {
+    let out = run_git_with_input_raw(args, input)?;
+    Ok(String::from_utf8_lossy(&out).to_string())
+}
+
+/// Like `run_git_with_input`, but stdout is returned as bytes (e.g. `cat-file --batch` blob content)
+pub(crate) fn run_git_with_input_raw(args: &[&str], input: &str) -> Result<Vec<u8>> {
     use std::io::Write;
 
     let repo_root = get_repo_root()?;
@@ -124,7 +137,62 @@ pub(crate) fn run_git_with_input(args: &[&str], input: &str) -> Result<String> {
         return Err(git_failed_error(args, &out));
     }
 
-    Ok(String::from_utf8_lossy(&out.stdout).to_string())
+    Ok(out.stdout)
+}
+
+/// Run git and hand each stdout line to `on_line` as it arrives, for output too large to buffer
+/// (invalid UTF-8 is replaced rather than rejected)
+pub(crate) fn for_each_git_line(args: &[&str], mut on_line: impl FnMut(&str)) -> Result<()> {
+    use std::io::BufRead;
+
+    let repo_root = get_repo_root()?;
+    let mut child = Command::new("git")
+        .current_dir(&repo_root)
+        .args(args)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to spawn git {}", args.join(" ")))?;
+
+    if let Some(stdout) = child.stdout.take() {
+        let mut reader = std::io::BufReader::new(stdout);
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            let read = reader
+                .read_until(b'\n', &mut buf)
+                .with_context(|| format!("Failed to read output of git {}", args.join(" ")))?;
+            if read == 0 {
+                break;
+            }
+            let line = String::from_utf8_lossy(&buf);
+            on_line(line.trim_end_matches(['\n', '\r']));
+        }
+    }
+
+    let out = child
+        .wait_with_output()
+        .with_context(|| format!("Failed to wait for git {}", args.join(" ")))?;
+    if !out.status.success() {
+        return Err(git_failed_error(args, &out));
+    }
+    Ok(())
+}
+
+/// Read a git config value; returns None when the key is unset
+pub(crate) fn config_get(key: &str) -> Result<Option<String>> {
+    let out = Command::new("git")
+        .args(["config", "--get", key])
+        .output()
+        .with_context(|| format!("Failed to run git config --get {key}"))?;
+    if !out.status.success() {
+        return Ok(None);
+    }
+    let value = String::from_utf8_lossy(&out.stdout).trim().to_string();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(value))
 }
 
 pub(crate) fn has_git_remote() -> Result<bool> {
@@ -474,13 +542,21 @@ pub(crate) fn safe_push(branch: &str, remote: &str, allow_force: bool) -> Result
             validation.warnings.join("; ")
         );
     }
-    
+
     if validation.needs_force && !allow_force {
         anyhow::bail!(
             "Force push required for '{}' but not allowed. Use --force flag to override.",
             branch
         );
     }
+
+    let operation = if validation.needs_force {
+        ProtectedOperation::ForcePush
+    } else {
+        ProtectedOperation::Push
+    };
+    crate::branch_policy::enforce(branch, operation)?;
+    crate::large_files::check_push(branch, remote)?;
     
     // Execute push
     let args = if validation.needs_force {
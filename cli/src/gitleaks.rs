@@ -0,0 +1,522 @@
+use anyhow::{Context, Result};
+use regex::{Regex, RegexBuilder, RegexSet, RegexSetBuilder};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// Default config file name, looked up at the repository root like gitleaks does
+pub(crate) const GITLEAKS_FILE: &str = ".gitleaks.toml";
+
+/// Inline marker gitleaks honours; respected whenever a gitleaks config is in use
+pub(crate) const GITLEAKS_INLINE_ALLOW: &str = "gitleaks:allow";
+
+/// Compile budget per regex (and for the combined set); the regex crate default is 10 MiB
+const REGEX_SIZE_LIMIT: usize = 64 << 20;
+
+/// gitleaks stops following `[extend] path` after this many levels
+const MAX_EXTEND_DEPTH: usize = 2;
+
+/// Rules from a gitleaks config compiled for scanning.
+/// Mirrors gitleaks v8 semantics so local results agree with CI.
+pub(crate) struct GitleaksRules {
+    /// Displayed path of the config that was loaded
+    pub(crate) source: String,
+    /// `[extend] useDefault = true`: run orca's built-in rules as well
+    pub(crate) use_default: bool,
+    /// `[extend] disabledRules`: rule ids to drop, including built-in ones
+    disabled_rules: Vec<String>,
+    rules: Vec<GitleaksRule>,
+    /// Regexes of `rules` that have one, for a single-pass prefilter
+    regex_set: RegexSet,
+    /// Index into `rules` for each pattern in `regex_set`
+    regex_rules: Vec<usize>,
+    /// Global `[allowlist]` / `[[allowlists]]`
+    allowlists: Vec<Allowlist>,
+}
+
+struct GitleaksRule {
+    id: String,
+    description: String,
+    regex: Option<Regex>,
+    path: Option<Regex>,
+    secret_group: usize,
+    entropy: f64,
+    keywords: Vec<String>,
+    allowlists: Vec<Allowlist>,
+}
+
+#[derive(Default)]
+struct Allowlist {
+    commits: Vec<String>,
+    paths: Vec<Regex>,
+    regexes: Vec<Regex>,
+    stopwords: Vec<String>,
+    target: RegexTarget,
+    require_all: bool,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum RegexTarget {
+    #[default]
+    Secret,
+    Match,
+    Line,
+}
+
+/// A secret found by a gitleaks rule
+pub(crate) struct GitleaksMatch<'r> {
+    pub(crate) rule_id: &'r str,
+    pub(crate) description: &'r str,
+    /// Byte range of the secret value within the line
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+// Raw TOML shape (gitleaks uses camelCase keys)
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawConfig {
+    #[serde(default)]
+    extend: RawExtend,
+    #[serde(default)]
+    rules: Vec<RawRule>,
+    allowlist: Option<RawAllowlist>,
+    #[serde(default)]
+    allowlists: Vec<RawAllowlist>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawExtend {
+    #[serde(default)]
+    use_default: bool,
+    path: Option<String>,
+    #[serde(default)]
+    disabled_rules: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawRule {
+    id: String,
+    #[serde(default)]
+    description: String,
+    regex: Option<String>,
+    path: Option<String>,
+    #[serde(default)]
+    secret_group: usize,
+    #[serde(default)]
+    entropy: f64,
+    #[serde(default)]
+    keywords: Vec<String>,
+    allowlist: Option<RawAllowlist>,
+    #[serde(default)]
+    allowlists: Vec<RawAllowlist>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawAllowlist {
+    #[serde(default)]
+    commits: Vec<String>,
+    #[serde(default)]
+    paths: Vec<String>,
+    #[serde(default)]
+    regexes: Vec<String>,
+    #[serde(default)]
+    stopwords: Vec<String>,
+    regex_target: Option<String>,
+    condition: Option<String>,
+}
+
+/// Locate the gitleaks config for a repository: `[secrets] gitleaks_config`,
+/// then `GITLEAKS_CONFIG`, then `.gitleaks.toml` at the root
+pub(crate) fn find_config(repo_root: &Path, configured: Option<&str>) -> Option<PathBuf> {
+    let explicit = configured
+        .map(str::to_string)
+        .or_else(|| std::env::var("GITLEAKS_CONFIG").ok())
+        .filter(|p| !p.trim().is_empty());
+    if let Some(path) = explicit {
+        return Some(repo_root.join(path));
+    }
+    let default = repo_root.join(GITLEAKS_FILE);
+    default.exists().then_some(default)
+}
+
+impl GitleaksRules {
+    pub(crate) fn load(path: &Path) -> Result<Self> {
+        let raw = read_raw(path, 0)?;
+        let source = path.file_name().map_or_else(
+            || path.display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        );
+        Self::compile(raw, source)
+    }
+
+    #[cfg(test)]
+    fn parse(content: &str) -> Result<Self> {
+        Self::compile(toml::from_str(content)?, GITLEAKS_FILE.to_string())
+    }
+
+    fn compile(raw: RawConfig, source: String) -> Result<Self> {
+        let mut rules = Vec::with_capacity(raw.rules.len());
+        for rule in raw.rules {
+            if raw.extend.disabled_rules.contains(&rule.id) {
+                continue;
+            }
+            rules.push(GitleaksRule::compile(rule)?);
+        }
+
+        let allowlists = raw
+            .allowlist
+            .into_iter()
+            .chain(raw.allowlists)
+            .map(|a| Allowlist::compile(a, "[allowlist]"))
+            .collect::<Result<_>>()?;
+
+        let regex_rules: Vec<usize> = (0..rules.len()).filter(|&i| rules[i].regex.is_some()).collect();
+        let regex_set = RegexSetBuilder::new(
+            regex_rules
+                .iter()
+                .filter_map(|&i| rules[i].regex.as_ref().map(Regex::as_str)),
+        )
+        .size_limit(REGEX_SIZE_LIMIT)
+        .build()
+        .context("Failed to combine gitleaks rules")?;
+
+        Ok(Self {
+            source,
+            use_default: raw.extend.use_default,
+            disabled_rules: raw.extend.disabled_rules,
+            rules,
+            regex_set,
+            regex_rules,
+            allowlists,
+        })
+    }
+
+    pub(crate) fn is_disabled(&self, rule_id: &str) -> bool {
+        self.disabled_rules.iter().any(|id| id == rule_id)
+    }
+
+    /// Paths excluded by the global allowlist are never scanned
+    pub(crate) fn allows_path(&self, file: &str) -> bool {
+        self.allowlists.iter().any(|a| {
+            let only_paths = a.commits.is_empty() && a.regexes.is_empty() && a.stopwords.is_empty();
+            a.path_allowed(file) && (!a.require_all || only_paths)
+        })
+    }
+
+    /// Rules with a `path` but no `regex` flag the file itself
+    pub(crate) fn flags_path(&self, file: &str) -> bool {
+        !self.allows_path(file)
+            && self.rules.iter().any(|r| {
+                r.regex.is_none() && r.path.as_ref().is_some_and(|p| p.is_match(file))
+            })
+    }
+
+    /// Secrets on one added line, in rule order
+    pub(crate) fn find(&self, line: &str, file: &str, commit: Option<&str>) -> Vec<GitleaksMatch<'_>> {
+        if self.allows_path(file) {
+            return Vec::new();
+        }
+        let candidates = self.regex_set.matches(line);
+        if !candidates.matched_any() {
+            return Vec::new();
+        }
+        let lower = line.to_lowercase();
+        let mut found = Vec::new();
+
+        for set_index in candidates.iter() {
+            let rule = &self.rules[self.regex_rules[set_index]];
+            let Some(regex) = &rule.regex else {
+                continue;
+            };
+            if !rule.keywords.is_empty() && !rule.keywords.iter().any(|k| lower.contains(k)) {
+                continue;
+            }
+            if let Some(path) = &rule.path
+                && !path.is_match(file)
+            {
+                continue;
+            }
+            for caps in regex.captures_iter(line) {
+                let Some(whole) = caps.get(0) else {
+                    continue;
+                };
+                // Explicit secretGroup, else the first non-empty capture group, else the match
+                let secret = if rule.secret_group > 0 {
+                    caps.get(rule.secret_group)
+                } else {
+                    caps.iter().skip(1).flatten().find(|m| !m.as_str().is_empty())
+                }
+                .unwrap_or(whole);
+
+                if rule.entropy > 0.0 {
+                    if crate::secret_rules::shannon_entropy(secret.as_str()) <= rule.entropy {
+                        continue;
+                    }
+                    if rule.id.starts_with("generic") && !secret.as_str().chars().any(|c| c.is_ascii_digit()) {
+                        continue;
+                    }
+                }
+
+                let candidate = Candidate {
+                    secret: secret.as_str(),
+                    whole: whole.as_str(),
+                    line,
+                    file,
+                    commit,
+                };
+                if self.allowlists.iter().chain(&rule.allowlists).any(|a| a.allows(&candidate)) {
+                    continue;
+                }
+
+                found.push(GitleaksMatch {
+                    rule_id: &rule.id,
+                    description: &rule.description,
+                    start: secret.start(),
+                    end: secret.end(),
+                });
+            }
+        }
+
+        found
+    }
+}
+
+/// Read a config, merging any `[extend] path` parent underneath it
+fn read_raw(path: &Path, depth: usize) -> Result<RawConfig> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read gitleaks config {}", path.display()))?;
+    let mut raw: RawConfig = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse gitleaks config {}", path.display()))?;
+
+    if let Some(parent) = raw.extend.path.take() {
+        if depth >= MAX_EXTEND_DEPTH {
+            anyhow::bail!(
+                "gitleaks config {} extends too deeply (max {} levels)",
+                path.display(),
+                MAX_EXTEND_DEPTH
+            );
+        }
+        let parent_path = path.parent().unwrap_or(Path::new(".")).join(parent);
+        let base = read_raw(&parent_path, depth + 1)?;
+
+        // Rules in the extending file replace parent rules with the same id
+        let mut rules: Vec<RawRule> = base
+            .rules
+            .into_iter()
+            .filter(|r| !raw.rules.iter().any(|own| own.id == r.id))
+            .collect();
+        rules.append(&mut raw.rules);
+        raw.rules = rules;
+
+        raw.allowlists.extend(base.allowlist);
+        raw.allowlists.extend(base.allowlists);
+        raw.extend.use_default |= base.extend.use_default;
+        raw.extend.disabled_rules.extend(base.extend.disabled_rules);
+    }
+
+    Ok(raw)
+}
+
+/// gitleaks is written in Go; RE2 syntax is close enough to the regex crate for its rules,
+/// but large Unicode classes need a bigger compile budget than the default
+fn compile_regex(pattern: &str, what: &str) -> Result<Regex> {
+    RegexBuilder::new(pattern)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .build()
+        .with_context(|| format!("Invalid regex in gitleaks {}", what))
+}
+
+impl GitleaksRule {
+    fn compile(raw: RawRule) -> Result<Self> {
+        let what = format!("rule '{}'", raw.id);
+        if raw.regex.is_none() && raw.path.is_none() {
+            anyhow::bail!("gitleaks {} needs a regex or a path", what);
+        }
+        let allowlists = raw
+            .allowlist
+            .into_iter()
+            .chain(raw.allowlists)
+            .map(|a| Allowlist::compile(a, &what))
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            regex: raw.regex.as_deref().map(|r| compile_regex(r, &what)).transpose()?,
+            path: raw.path.as_deref().map(|p| compile_regex(p, &what)).transpose()?,
+            keywords: raw.keywords.iter().map(|k| k.to_lowercase()).collect(),
+            description: if raw.description.is_empty() {
+                raw.id.clone()
+            } else {
+                raw.description
+            },
+            id: raw.id,
+            secret_group: raw.secret_group,
+            entropy: raw.entropy,
+            allowlists,
+        })
+    }
+}
+
+/// What an allowlist is checked against
+struct Candidate<'a> {
+    secret: &'a str,
+    whole: &'a str,
+    line: &'a str,
+    file: &'a str,
+    commit: Option<&'a str>,
+}
+
+impl Allowlist {
+    fn compile(raw: RawAllowlist, what: &str) -> Result<Self> {
+        let target = match raw.regex_target.as_deref() {
+            None | Some("") | Some("secret") => RegexTarget::Secret,
+            Some("match") => RegexTarget::Match,
+            Some("line") => RegexTarget::Line,
+            Some(other) => anyhow::bail!("Unknown regexTarget '{}' in gitleaks {}", other, what),
+        };
+        let require_all = match raw.condition.as_deref().map(str::to_ascii_uppercase).as_deref() {
+            None | Some("") | Some("OR") => false,
+            Some("AND") => true,
+            Some(other) => anyhow::bail!("Unknown allowlist condition '{}' in gitleaks {}", other, what),
+        };
+        let compile_all = |patterns: &[String]| -> Result<Vec<Regex>> {
+            patterns.iter().map(|p| compile_regex(p, what)).collect()
+        };
+        Ok(Self {
+            paths: compile_all(&raw.paths)?,
+            regexes: compile_all(&raw.regexes)?,
+            commits: raw.commits,
+            stopwords: raw.stopwords.iter().map(|s| s.to_lowercase()).collect(),
+            target,
+            require_all,
+        })
+    }
+
+    fn path_allowed(&self, file: &str) -> bool {
+        self.paths.iter().any(|p| p.is_match(file))
+    }
+
+    /// OR: any configured check matches. AND: every configured check matches.
+    fn allows(&self, candidate: &Candidate) -> bool {
+        let regex_input = match self.target {
+            RegexTarget::Secret => candidate.secret,
+            RegexTarget::Match => candidate.whole,
+            RegexTarget::Line => candidate.line,
+        };
+        let secret = candidate.secret.to_lowercase();
+
+        // (configured, matched) per criterion
+        let checks = [
+            (
+                !self.commits.is_empty(),
+                candidate.commit.is_some_and(|c| self.commits.iter().any(|a| a == c)),
+            ),
+            (!self.paths.is_empty(), self.path_allowed(candidate.file)),
+            (
+                !self.regexes.is_empty(),
+                self.regexes.iter().any(|r| r.is_match(regex_input)),
+            ),
+            (
+                !self.stopwords.is_empty(),
+                self.stopwords.iter().any(|s| secret.contains(s.as_str())),
+            ),
+        ];
+
+        let mut configured = checks.iter().filter(|(configured, _)| *configured).peekable();
+        if configured.peek().is_none() {
+            return false;
+        }
+        if self.require_all {
+            configured.all(|(_, matched)| *matched)
+        } else {
+            configured.any(|(_, matched)| *matched)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+title = "test"
+
+[extend]
+disabledRules = ["password-assignment"]
+
+[[rules]]
+id = "internal-token"
+description = "Internal Service Token"
+regex = '''\b(itk_[a-z0-9]{24})\b'''
+keywords = ["itk_"]
+[rules.allowlist]
+stopwords = ["sample"]
+
+[[rules]]
+id = "generic-secret"
+regex = '''secret\s*=\s*"([^"]+)"'''
+entropy = 3.0
+
+[[rules]]
+id = "pkcs12-file"
+path = '''\.p12$'''
+
+[allowlist]
+paths = ['''^vendor/''']
+regexes = ['''^itk_0{24}$''']
+"#;
+
+    fn ids(rules: &GitleaksRules, line: &str, file: &str) -> Vec<String> {
+        rules.find(line, file, None).iter().map(|m| m.rule_id.to_string()).collect()
+    }
+
+    #[test]
+    fn applies_rules_keywords_entropy_and_groups() {
+        let rules = GitleaksRules::parse(CONFIG).unwrap();
+        assert!(!rules.use_default);
+        assert!(rules.is_disabled("password-assignment"));
+
+        let line = "token = itk_abcdefghij0123456789klmn";
+        let found = rules.find(line, "src/app.rs", None);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].description, "Internal Service Token");
+        assert_eq!(&line[found[0].start..found[0].end], "itk_abcdefghij0123456789klmn");
+
+        // generic-* rules need a digit, and entropy must exceed the threshold
+        assert!(ids(&rules, r#"secret = "abcdefghijkl""#, "a.rs").is_empty());
+        assert_eq!(ids(&rules, r#"secret = "abcdefghijk1""#, "a.rs"), vec!["generic-secret"]);
+        assert!(ids(&rules, r#"secret = "aaaaaaaaaaa1""#, "a.rs").is_empty());
+    }
+
+    #[test]
+    fn honours_global_and_rule_allowlists() {
+        let rules = GitleaksRules::parse(CONFIG).unwrap();
+        assert!(ids(&rules, "itk_abcdefghij0123456789klmn", "vendor/lib.js").is_empty());
+        assert!(ids(&rules, "itk_000000000000000000000000", "src/a.rs").is_empty());
+        assert!(ids(&rules, "itk_sample0123456789abcdefgh", "src/a.rs").is_empty());
+
+        assert!(rules.flags_path("certs/client.p12"));
+        assert!(!rules.flags_path("vendor/client.p12"));
+        assert!(!rules.flags_path("src/main.rs"));
+    }
+
+    #[test]
+    fn and_condition_requires_every_check() {
+        let config = r#"
+[[rules]]
+id = "key"
+regex = '''key_[a-z0-9]{8}'''
+[[rules.allowlists]]
+condition = "AND"
+paths = ['''^docs/''']
+regexTarget = "line"
+regexes = ['''example''']
+"#;
+        let rules = GitleaksRules::parse(config).unwrap();
+        assert!(ids(&rules, "key_abcd1234 example", "docs/a.md").is_empty());
+        assert_eq!(ids(&rules, "key_abcd1234", "docs/a.md"), vec!["key"]);
+        assert_eq!(ids(&rules, "key_abcd1234 example", "src/a.rs"), vec!["key"]);
+    }
+}
@@ -0,0 +1,271 @@
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use std::path::{Path, PathBuf};
+
+/// First comment line of every hook script orca writes; anything without it is left alone
+const MANAGED_MARKER: &str = "# orca-managed-hook:";
+
+/// A pre-existing hook is renamed to `<hook>.orca-chained` and run before orca's checks
+const CHAINED_SUFFIX: &str = ".orca-chained";
+
+/// Git hooks orca can manage
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum HookKind {
+    /// Secret scan of the staged content
+    PreCommit,
+    /// Commit message lint
+    CommitMsg,
+    /// AI-drafted message for a plain `git commit`
+    PrepareCommitMsg,
+    /// Push preflight checks
+    PrePush,
+}
+
+impl HookKind {
+    pub(crate) const ALL: [HookKind; 4] = [
+        HookKind::PreCommit,
+        HookKind::CommitMsg,
+        HookKind::PrepareCommitMsg,
+        HookKind::PrePush,
+    ];
+
+    /// Installed when no `--hook` is given; prepare-commit-msg calls the AI, so it is opt-in
+    pub(crate) const DEFAULT: [HookKind; 3] = [HookKind::PreCommit, HookKind::CommitMsg, HookKind::PrePush];
+
+    pub(crate) fn file_name(self) -> &'static str {
+        match self {
+            HookKind::PreCommit => "pre-commit",
+            HookKind::CommitMsg => "commit-msg",
+            HookKind::PrepareCommitMsg => "prepare-commit-msg",
+            HookKind::PrePush => "pre-push",
+        }
+    }
+
+    pub(crate) fn description(self) -> &'static str {
+        match self {
+            HookKind::PreCommit => "secret scan of staged changes",
+            HookKind::CommitMsg => "commit message lint",
+            HookKind::PrepareCommitMsg => "AI-drafted commit message",
+            HookKind::PrePush => "push preflight",
+        }
+    }
+}
+
+/// What currently sits at a hook's path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HookState {
+    Missing,
+    /// orca's script; `chained` when an earlier hook runs first
+    Managed { chained: bool },
+    /// A hook orca did not write
+    Foreign,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InstallOutcome {
+    Installed,
+    /// An existing managed hook was rewritten
+    Updated,
+    /// An existing hook was kept and now runs before orca's
+    Chained,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UninstallOutcome {
+    Removed,
+    /// The chained hook was moved back into place
+    Restored,
+    NotManaged,
+    Missing,
+}
+
+/// Directory git runs hooks from; honours `core.hooksPath` and linked worktrees
+pub(crate) fn hooks_dir() -> Result<PathBuf> {
+    let raw = crate::git::run_git(&["rev-parse", "--git-path", "hooks"])?;
+    let path = PathBuf::from(raw.trim());
+    if path.is_absolute() {
+        return Ok(path);
+    }
+    // run_git runs at the repository root, so relative paths are relative to it
+    Ok(crate::git::get_repo_root()?.join(path))
+}
+
+/// `core.hooksPath`, when set
+pub(crate) fn configured_hooks_path() -> Option<String> {
+    crate::git::run_git(&["config", "--get", "core.hooksPath"])
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn hook_path(dir: &Path, kind: HookKind) -> PathBuf {
+    dir.join(kind.file_name())
+}
+
+fn chained_path(dir: &Path, kind: HookKind) -> PathBuf {
+    dir.join(format!("{}{}", kind.file_name(), CHAINED_SUFFIX))
+}
+
+fn is_managed(path: &Path) -> bool {
+    std::fs::read_to_string(path)
+        .map(|content| content.lines().take(3).any(|l| l.starts_with(MANAGED_MARKER)))
+        .unwrap_or(false)
+}
+
+pub(crate) fn hook_state(dir: &Path, kind: HookKind) -> HookState {
+    let path = hook_path(dir, kind);
+    if !path.exists() {
+        HookState::Missing
+    } else if is_managed(&path) {
+        HookState::Managed {
+            chained: chained_path(dir, kind).exists(),
+        }
+    } else {
+        HookState::Foreign
+    }
+}
+
+/// POSIX sh script that runs any chained hook, then `orca hooks run <hook>`.
+/// `orca_bin` is the absolute path at install time, so IDEs with a minimal
+/// PATH still find orca; PATH is the fallback after a reinstall elsewhere.
+pub(crate) fn render_script(kind: HookKind, orca_bin: &str) -> String {
+    let name = kind.file_name();
+    format!(
+        r#"#!/bin/sh
+{marker} {name}
+# Installed by `orca hooks install`; `orca hooks uninstall` removes it and
+# restores {name}{suffix} if present. Skip once with `git {skip} --no-verify`.
+
+chained="$(dirname "$0")/{name}{suffix}"
+if [ -x "$chained" ]; then
+    "$chained" "$@" || exit $?
+fi
+
+orca={orca}
+[ -x "$orca" ] || orca=orca
+if ! command -v "$orca" >/dev/null 2>&1; then
+    echo "orca not found; skipping {name} checks" >&2
+    exit 0
+fi
+exec "$orca" hooks run {name} "$@"
+"#,
+        marker = MANAGED_MARKER,
+        name = name,
+        suffix = CHAINED_SUFFIX,
+        skip = if kind == HookKind::PrePush { "push" } else { "commit" },
+        orca = shell_quote(orca_bin),
+    )
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// Write orca's hook, moving a foreign hook aside so it keeps running first
+pub(crate) fn install_hook(dir: &Path, kind: HookKind, orca_bin: &str) -> Result<InstallOutcome> {
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let path = hook_path(dir, kind);
+    let chained = chained_path(dir, kind);
+
+    let outcome = match hook_state(dir, kind) {
+        HookState::Missing => InstallOutcome::Installed,
+        HookState::Managed { .. } => InstallOutcome::Updated,
+        HookState::Foreign => {
+            if chained.exists() {
+                anyhow::bail!(
+                    "Both {} and {} exist; merge or remove one of them first",
+                    path.display(),
+                    chained.display()
+                );
+            }
+            std::fs::rename(&path, &chained)
+                .with_context(|| format!("Failed to move {} aside", path.display()))?;
+            InstallOutcome::Chained
+        }
+    };
+
+    std::fs::write(&path, render_script(kind, orca_bin))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    make_executable(&path)?;
+    Ok(outcome)
+}
+
+/// Remove orca's hook and move a chained hook back into place
+pub(crate) fn uninstall_hook(dir: &Path, kind: HookKind) -> Result<UninstallOutcome> {
+    let path = hook_path(dir, kind);
+    match hook_state(dir, kind) {
+        HookState::Missing => Ok(UninstallOutcome::Missing),
+        HookState::Foreign => Ok(UninstallOutcome::NotManaged),
+        HookState::Managed { chained } => {
+            std::fs::remove_file(&path).with_context(|| format!("Failed to remove {}", path.display()))?;
+            if !chained {
+                return Ok(UninstallOutcome::Removed);
+            }
+            std::fs::rename(chained_path(dir, kind), &path)
+                .with_context(|| format!("Failed to restore {}", path.display()))?;
+            Ok(UninstallOutcome::Restored)
+        }
+    }
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut permissions = std::fs::metadata(path)?.permissions();
+    permissions.set_mode(permissions.mode() | 0o755);
+    std::fs::set_permissions(path, permissions).with_context(|| format!("Failed to make {} executable", path.display()))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    // Git for Windows runs hooks through its bundled sh regardless of file mode
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_hooks_dir() -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("orca_hooks_test_{}", nanos))
+    }
+
+    #[test]
+    fn install_chains_existing_hook_and_uninstall_restores_it() {
+        let dir = temp_hooks_dir();
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("pre-commit"), "#!/bin/sh\nnpm run lint\n").unwrap();
+
+        let outcome = install_hook(&dir, HookKind::PreCommit, "/opt/orca/bin/orca").unwrap();
+        assert_eq!(outcome, InstallOutcome::Chained);
+        assert_eq!(hook_state(&dir, HookKind::PreCommit), HookState::Managed { chained: true });
+        assert_eq!(
+            install_hook(&dir, HookKind::PreCommit, "/opt/orca/bin/orca").unwrap(),
+            InstallOutcome::Updated
+        );
+        assert_eq!(install_hook(&dir, HookKind::PrePush, "orca").unwrap(), InstallOutcome::Installed);
+
+        assert_eq!(uninstall_hook(&dir, HookKind::PreCommit).unwrap(), UninstallOutcome::Restored);
+        assert_eq!(
+            std::fs::read_to_string(dir.join("pre-commit")).unwrap(),
+            "#!/bin/sh\nnpm run lint\n"
+        );
+        assert_eq!(uninstall_hook(&dir, HookKind::PreCommit).unwrap(), UninstallOutcome::NotManaged);
+        assert_eq!(uninstall_hook(&dir, HookKind::PrePush).unwrap(), UninstallOutcome::Removed);
+        assert_eq!(uninstall_hook(&dir, HookKind::CommitMsg).unwrap(), UninstallOutcome::Missing);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn script_quotes_the_orca_path() {
+        let script = render_script(HookKind::CommitMsg, "/home/o'neil/bin/orca");
+        assert!(script.starts_with("#!/bin/sh\n# orca-managed-hook: commit-msg\n"));
+        assert!(script.contains(r"orca='/home/o'\''neil/bin/orca'"));
+        assert!(script.contains(r#"exec "$orca" hooks run commit-msg "$@""#));
+    }
+}
@@ -0,0 +1,229 @@
+use crate::cli::CommitStylePreset;
+use crate::config::{IssueConfig, IssuePattern};
+use regex::Regex;
+
+/// Built-in branch patterns: Jira/Linear keys (`feat/PROJ-123-login`) and GitHub issue numbers (`123-fix-crash`)
+const DEFAULT_PATTERNS: &[(&str, &str)] = &[
+    (r"(?:^|[/_-])([A-Z][A-Z0-9]+-\d+)(?:$|[/_-])", "$1"),
+    (r"(?:^|/)(\d+)(?:$|[-_])", "#$1"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Placement {
+    Prefix,
+    Suffix,
+}
+
+impl Placement {
+    fn from_config(value: Option<&str>) -> Option<Self> {
+        match value?.trim().to_lowercase().as_str() {
+            "prefix" => Some(Placement::Prefix),
+            "suffix" => Some(Placement::Suffix),
+            _ => None,
+        }
+    }
+
+    /// Keys like `PROJ-123` lead the subject, GitHub refs like `#123` trail it
+    fn default_for(key: &str) -> Self {
+        if key.starts_with('#') {
+            Placement::Suffix
+        } else {
+            Placement::Prefix
+        }
+    }
+}
+
+/// Issue key resolved for the current branch, along with how to inject it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct IssueKey {
+    pub(crate) key: String,
+    placement: Option<Placement>,
+}
+
+impl IssueKey {
+    /// Inject the key into the subject line of a (possibly multi-line) message
+    pub(crate) fn inject(&self, message: &str) -> String {
+        match message.split_once('\n') {
+            Some((subject, body)) => {
+                format!("{}\n{}", inject_key(subject, &self.key, self.placement), body)
+            }
+            None => inject_key(message, &self.key, self.placement),
+        }
+    }
+}
+
+/// Whether keys should be injected for the given style preset
+pub(crate) fn should_inject(config: &IssueConfig, preset: Option<CommitStylePreset>) -> bool {
+    config.inject.unwrap_or(matches!(
+        preset,
+        Some(CommitStylePreset::Jira | CommitStylePreset::Linear | CommitStylePreset::Github)
+    ))
+}
+
+/// Issue key inferred from the current branch name using the configured patterns
+pub(crate) fn branch_issue_key(config: &IssueConfig) -> Option<IssueKey> {
+    let branch = crate::git::current_branch().ok()?;
+    infer_from_branch(&branch, &config.patterns).map(|key| IssueKey {
+        key,
+        placement: Placement::from_config(config.placement.as_deref()),
+    })
+}
+
+/// Load config and resolve the key to inject, if injection applies
+pub(crate) fn key_to_inject(preset: Option<CommitStylePreset>) -> Option<IssueKey> {
+    let config = crate::config::load_config().ok()?;
+    if !should_inject(&config.issues, preset) {
+        return None;
+    }
+    branch_issue_key(&config.issues)
+}
+
+/// Key for a PR title: the one already carried by the commits, else the branch key if injection applies
+pub(crate) fn pr_title_key(from_commits: Option<String>) -> Option<IssueKey> {
+    let config = crate::config::load_config().unwrap_or_default();
+    match from_commits {
+        Some(key) => Some(IssueKey {
+            key,
+            placement: Placement::from_config(config.issues.placement.as_deref()),
+        }),
+        None => {
+            let preset = config
+                .git
+                .commit_style
+                .as_deref()
+                .and_then(CommitStylePreset::from_style);
+            if should_inject(&config.issues, preset) {
+                branch_issue_key(&config.issues)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+pub(crate) fn infer_from_branch(branch: &str, patterns: &[IssuePattern]) -> Option<String> {
+    let compiled: Vec<(Regex, String)> = if patterns.is_empty() {
+        DEFAULT_PATTERNS
+            .iter()
+            .filter_map(|(re, fmt)| Regex::new(re).ok().map(|r| (r, fmt.to_string())))
+            .collect()
+    } else {
+        patterns
+            .iter()
+            .filter_map(|p| match Regex::new(&p.regex) {
+                Ok(r) => Some((r, p.format.clone())),
+                Err(e) => {
+                    eprintln!("Warning: ignoring invalid issue pattern '{}': {}", p.regex, e);
+                    None
+                }
+            })
+            .collect()
+    };
+
+    for (regex, format) in &compiled {
+        if let Some(caps) = regex.captures(branch) {
+            let mut key = String::new();
+            caps.expand(format, &mut key);
+            let key = key.trim().to_string();
+            if !key.is_empty() && key != "#" {
+                return Some(key);
+            }
+        }
+    }
+    None
+}
+
+/// Split a subject into its issue key (leading `PROJ-123` or trailing `(#123)`) and the bare subject
+pub(crate) fn split_key(subject: &str) -> (Option<String>, String) {
+    let subject = subject.trim();
+
+    if let Ok(prefix) = Regex::new(r"^\[?([A-Z][A-Z0-9]+-\d+)\]?:?\s+(.+)$")
+        && let Some(caps) = prefix.captures(subject)
+    {
+        return (Some(caps[1].to_string()), caps[2].trim().to_string());
+    }
+
+    if let Ok(suffix) = Regex::new(r"^(.+?)\s*\((#\d+)\)$")
+        && let Some(caps) = suffix.captures(subject)
+    {
+        return (Some(caps[2].to_string()), caps[1].trim().to_string());
+    }
+
+    (None, subject.to_string())
+}
+
+fn inject_key(subject: &str, key: &str, placement: Option<Placement>) -> String {
+    let subject = subject.trim();
+    if subject.contains(key) {
+        return subject.to_string();
+    }
+
+    match placement.unwrap_or_else(|| Placement::default_for(key)) {
+        Placement::Prefix => format!("{key} {subject}"),
+        Placement::Suffix => format!("{subject} ({key})"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infers_default_keys_from_branch() {
+        assert_eq!(infer_from_branch("feat/PROJ-123-login", &[]), Some("PROJ-123".to_string()));
+        assert_eq!(infer_from_branch("ENG-42", &[]), Some("ENG-42".to_string()));
+        assert_eq!(infer_from_branch("123-fix-crash", &[]), Some("#123".to_string()));
+        assert_eq!(infer_from_branch("fix/123-crash", &[]), Some("#123".to_string()));
+        assert_eq!(infer_from_branch("feat/login-v2", &[]), None);
+        assert_eq!(infer_from_branch("main", &[]), None);
+    }
+
+    #[test]
+    fn configured_patterns_replace_defaults() {
+        let patterns = vec![IssuePattern {
+            regex: r"^[a-z]+/gh-(\d+)".to_string(),
+            format: "#$1".to_string(),
+        }];
+        assert_eq!(infer_from_branch("fix/gh-77-crash", &patterns), Some("#77".to_string()));
+        assert_eq!(infer_from_branch("feat/PROJ-1-x", &patterns), None);
+    }
+
+    #[test]
+    fn injects_key_once_with_default_placement() {
+        assert_eq!(inject_key("feat: add login", "PROJ-1", None), "PROJ-1 feat: add login");
+        assert_eq!(inject_key("fix: crash", "#12", None), "fix: crash (#12)");
+        assert_eq!(inject_key("PROJ-1 feat: add login", "PROJ-1", None), "PROJ-1 feat: add login");
+        assert_eq!(
+            inject_key("feat: add login", "PROJ-1", Some(Placement::Suffix)),
+            "feat: add login (PROJ-1)"
+        );
+    }
+
+    #[test]
+    fn splits_prefix_and_suffix_keys() {
+        assert_eq!(
+            split_key("PROJ-123 feat: add login"),
+            (Some("PROJ-123".to_string()), "feat: add login".to_string())
+        );
+        assert_eq!(
+            split_key("fix: crash on start (#12)"),
+            (Some("#12".to_string()), "fix: crash on start".to_string())
+        );
+        assert_eq!(split_key("chore: tidy"), (None, "chore: tidy".to_string()));
+    }
+
+    #[test]
+    fn injection_defaults_to_issue_presets() {
+        let config = IssueConfig::default();
+        assert!(should_inject(&config, Some(CommitStylePreset::Jira)));
+        assert!(should_inject(&config, Some(CommitStylePreset::Github)));
+        assert!(!should_inject(&config, Some(CommitStylePreset::Conventional)));
+        assert!(!should_inject(&config, None));
+
+        let forced = IssueConfig {
+            inject: Some(true),
+            ..Default::default()
+        };
+        assert!(should_inject(&forced, None));
+    }
+}
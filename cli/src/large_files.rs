@@ -0,0 +1,424 @@
+use crate::config::LargeFilesConfig;
+use crate::git::{run_git, run_git_with_input, run_git_with_input_raw};
+use crate::secret_scan::ScanTarget;
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+const DEFAULT_MAX_FILE_SIZE_KB: u64 = 5120;
+
+/// git-lfs pointer files are always smaller than this
+const MAX_POINTER_BYTES: u64 = 1024;
+
+const LFS_POINTER_PREFIX: &str = "version https://git-lfs.github.com/spec/";
+
+/// Objects or paths per `git cat-file`/`git check-attr` call. The whole input is written
+/// before output is read, so each call's output has to fit in the pipe buffer.
+const BATCH: usize = 500;
+
+/// Small blobs read per `git cat-file --batch` call (each is under MAX_POINTER_BYTES)
+const CONTENT_BATCH: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum BlobOrigin {
+    /// Staged or tracked in the index; fixable without rewriting history
+    Index,
+    /// Part of a commit; needs a history rewrite to remove
+    Commit,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct LargeBlob {
+    pub(crate) path: String,
+    pub(crate) oid: String,
+    pub(crate) size: u64,
+    pub(crate) origin: BlobOrigin,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct LargeFileReport {
+    /// Size limit in bytes
+    pub(crate) limit: u64,
+    pub(crate) blobs_checked: usize,
+    /// Blobs over the limit at paths Git LFS does not track
+    pub(crate) oversized: Vec<LargeBlob>,
+    /// Blobs at LFS-tracked paths that were stored as regular blobs instead of pointers
+    pub(crate) not_in_lfs: Vec<LargeBlob>,
+    /// The blobs span every ref, so a fix has to rewrite all of them
+    #[serde(skip)]
+    pub(crate) all_refs: bool,
+}
+
+impl LargeFileReport {
+    pub(crate) fn issue_count(&self) -> usize {
+        self.oversized.len() + self.not_in_lfs.len()
+    }
+
+    /// One line for check results and push errors
+    pub(crate) fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.oversized.is_empty() {
+            parts.push(format!(
+                "{} file(s) over {}: {}",
+                self.oversized.len(),
+                human_size(self.limit),
+                list_blobs(&self.oversized)
+            ));
+        }
+        if !self.not_in_lfs.is_empty() {
+            parts.push(format!(
+                "{} LFS-tracked file(s) committed without LFS: {}",
+                self.not_in_lfs.len(),
+                list_blobs(&self.not_in_lfs)
+            ));
+        }
+        parts.join("; ")
+    }
+}
+
+fn list_blobs(blobs: &[LargeBlob]) -> String {
+    const LISTED: usize = 3;
+    let mut listed: Vec<String> = blobs
+        .iter()
+        .take(LISTED)
+        .map(|b| format!("{} ({})", b.path, human_size(b.size)))
+        .collect();
+    if blobs.len() > LISTED {
+        listed.push(format!("and {} more", blobs.len() - LISTED));
+    }
+    listed.join(", ")
+}
+
+pub(crate) fn human_size(bytes: u64) -> String {
+    const MIB: u64 = 1024 * 1024;
+    if bytes >= MIB {
+        format!("{:.1} MiB", bytes as f64 / MIB as f64)
+    } else {
+        format!("{} KiB", bytes.div_ceil(1024))
+    }
+}
+
+pub(crate) fn limit_bytes(config: &LargeFilesConfig) -> u64 {
+    config.max_file_size_kb.unwrap_or(DEFAULT_MAX_FILE_SIZE_KB) * 1024
+}
+
+/// Check the blobs a scan target covers: staged files, the index, the blobs
+/// introduced by a commit range, or every blob in history
+pub(crate) fn scan_large_files(target: &ScanTarget, limit: u64) -> Result<LargeFileReport> {
+    let entries = match target {
+        ScanTarget::Staged => staged_entries()?,
+        ScanTarget::All => index_entries(None)?,
+        ScanTarget::Range(range) => rev_list_entries(&[range.as_str()])?,
+        ScanTarget::History { since } => {
+            let not_since = since.as_ref().map(|s| format!("^{}", s));
+            let mut revs = vec!["--all"];
+            revs.extend(not_since.as_deref());
+            rev_list_entries(&revs)?
+        }
+    };
+    let mut report = analyze(entries, limit)?;
+    report.all_refs = matches!(target, ScanTarget::History { .. });
+    Ok(report)
+}
+
+/// Blobs a push of `rev` would upload: reachable from it but not from any ref of
+/// `remote` (of any remote when None), plus the staged ones when `include_staged`
+pub(crate) fn scan_unpushed(rev: &str, remote: Option<&str>, include_staged: bool, limit: u64) -> Result<LargeFileReport> {
+    let remotes = match remote {
+        Some(remote) => format!("--remotes={}", remote),
+        None => "--remotes".to_string(),
+    };
+    let mut entries = rev_list_entries(&[rev, "--not", &remotes])?;
+    if include_staged {
+        entries.extend(staged_entries()?);
+    }
+    analyze(entries, limit)
+}
+
+/// Refuse an orca push that would upload flagged blobs, unless `[large_files] allow_push` is set
+pub(crate) fn check_push(branch: &str, remote: &str) -> Result<()> {
+    let config = crate::config::load_config()?.large_files;
+    if config.allow_push {
+        return Ok(());
+    }
+    let report = scan_unpushed(branch, Some(remote), false, limit_bytes(&config))?;
+    if report.issue_count() == 0 {
+        return Ok(());
+    }
+
+    let fixes: Vec<String> = remediation(&report).iter().map(|c| format!("  {}", c)).collect();
+    anyhow::bail!(
+        "Refusing to push '{}': {}\nMove them to Git LFS first:\n{}\nOr set `allow_push = true` under [large_files] to push anyway",
+        branch,
+        report.summary(),
+        fixes.join("\n")
+    )
+}
+
+struct Entry {
+    oid: String,
+    path: String,
+    origin: BlobOrigin,
+}
+
+/// `git ls-files -s` entries, optionally only for `only` paths; gitlinks are skipped
+fn index_entries(only: Option<&HashSet<String>>) -> Result<Vec<Entry>> {
+    let out = run_git(&["ls-files", "-s", "-z"])?;
+    Ok(out
+        .split('\0')
+        .filter_map(|record| {
+            let (meta, path) = record.split_once('\t')?;
+            let mut fields = meta.split_whitespace();
+            let mode = fields.next()?;
+            let oid = fields.next()?;
+            if mode == "160000" || only.is_some_and(|only| !only.contains(path)) {
+                return None;
+            }
+            Some(Entry {
+                oid: oid.to_string(),
+                path: path.to_string(),
+                origin: BlobOrigin::Index,
+            })
+        })
+        .collect())
+}
+
+fn staged_entries() -> Result<Vec<Entry>> {
+    let changed = run_git(&["diff", "--cached", "--name-only", "--diff-filter=AM", "-z"])?;
+    let changed: HashSet<String> = changed.split('\0').filter(|p| !p.is_empty()).map(str::to_string).collect();
+    if changed.is_empty() {
+        return Ok(Vec::new());
+    }
+    index_entries(Some(&changed))
+}
+
+/// Objects `git rev-list --objects` lists with a path; commits have none, trees are
+/// dropped once their type is known
+fn rev_list_entries(revs: &[&str]) -> Result<Vec<Entry>> {
+    let mut args = vec!["rev-list", "--objects"];
+    args.extend_from_slice(revs);
+    let out = run_git(&args)?;
+    Ok(out
+        .lines()
+        .filter_map(|line| {
+            let (oid, path) = line.split_once(' ')?;
+            (!path.is_empty()).then(|| Entry {
+                oid: oid.to_string(),
+                path: path.to_string(),
+                origin: BlobOrigin::Commit,
+            })
+        })
+        .collect())
+}
+
+fn analyze(entries: Vec<Entry>, limit: u64) -> Result<LargeFileReport> {
+    let mut report = LargeFileReport {
+        limit,
+        ..Default::default()
+    };
+
+    let sizes = blob_sizes(&entries)?;
+    let blobs: Vec<(Entry, u64)> = entries
+        .into_iter()
+        .filter_map(|e| sizes.get(&e.oid).copied().map(|size| (e, size)))
+        .collect();
+    report.blobs_checked = blobs.len();
+
+    let paths: BTreeSet<&str> = blobs.iter().map(|(e, _)| e.path.as_str()).collect();
+    let lfs_paths = lfs_tracked(&paths)?;
+    let pointer_candidates: Vec<&str> = blobs
+        .iter()
+        .filter(|(e, size)| lfs_paths.contains(&e.path) && *size < MAX_POINTER_BYTES)
+        .map(|(e, _)| e.oid.as_str())
+        .collect();
+    let pointers = lfs_pointers(&pointer_candidates)?;
+
+    for (entry, size) in blobs {
+        let blob = LargeBlob {
+            path: entry.path,
+            oid: entry.oid,
+            size,
+            origin: entry.origin,
+        };
+        if lfs_paths.contains(&blob.path) {
+            if !pointers.contains(&blob.oid) {
+                report.not_in_lfs.push(blob);
+            }
+        } else if size > limit {
+            report.oversized.push(blob);
+        }
+    }
+    report.oversized.sort_by_key(|b| std::cmp::Reverse(b.size));
+    report.not_in_lfs.sort_by_key(|b| std::cmp::Reverse(b.size));
+    Ok(report)
+}
+
+/// Sizes of the entries that are blobs; trees and missing objects are left out
+fn blob_sizes(entries: &[Entry]) -> Result<HashMap<String, u64>> {
+    let mut sizes = HashMap::new();
+    let oids: Vec<&str> = entries
+        .iter()
+        .map(|e| e.oid.as_str())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    for chunk in oids.chunks(BATCH) {
+        let out = run_git_with_input(
+            &["cat-file", "--batch-check=%(objecttype) %(objectname) %(objectsize)"],
+            &format!("{}\n", chunk.join("\n")),
+        )?;
+        for line in out.lines() {
+            let mut fields = line.split(' ');
+            if let (Some("blob"), Some(oid), Some(size)) = (fields.next(), fields.next(), fields.next())
+                && let Ok(size) = size.parse()
+            {
+                sizes.insert(oid.to_string(), size);
+            }
+        }
+    }
+    Ok(sizes)
+}
+
+/// Paths whose `filter` attribute is `lfs`, per the current .gitattributes
+fn lfs_tracked(paths: &BTreeSet<&str>) -> Result<HashSet<String>> {
+    let mut tracked = HashSet::new();
+    let paths: Vec<&str> = paths.iter().copied().collect();
+    for chunk in paths.chunks(BATCH) {
+        let mut input = chunk.join("\0");
+        input.push('\0');
+        let out = run_git_with_input(&["check-attr", "-z", "--stdin", "filter"], &input)?;
+        // NUL-separated triples: path, attribute, value
+        let fields: Vec<&str> = out.split('\0').collect();
+        for triple in fields.chunks(3) {
+            if let [path, _, "lfs"] = triple {
+                tracked.insert(path.to_string());
+            }
+        }
+    }
+    Ok(tracked)
+}
+
+/// Which of the given small blobs are git-lfs pointer files
+fn lfs_pointers(oids: &[&str]) -> Result<HashSet<String>> {
+    let mut pointers = HashSet::new();
+    for chunk in oids.chunks(CONTENT_BATCH) {
+        let out = run_git_with_input_raw(&["cat-file", "--batch"], &format!("{}\n", chunk.join("\n")))?;
+        // "<oid> blob <size>\n<content>\n" per object; content may be binary
+        let mut rest = out.as_slice();
+        while let Some(newline) = rest.iter().position(|b| *b == b'\n') {
+            let header = String::from_utf8_lossy(&rest[..newline]);
+            let mut fields = header.split(' ');
+            let (Some(oid), Some(_), Some(Ok(size))) = (fields.next(), fields.next(), fields.next().map(str::parse::<usize>))
+            else {
+                break;
+            };
+            let body = &rest[newline + 1..];
+            if body.get(..size).is_some_and(|c| c.starts_with(LFS_POINTER_PREFIX.as_bytes())) {
+                pointers.insert(oid.to_string());
+            }
+            rest = body.get(size + 1..).unwrap_or_default();
+        }
+    }
+    Ok(pointers)
+}
+
+pub(crate) fn lfs_available() -> bool {
+    run_git(&["lfs", "version"]).is_ok()
+}
+
+/// `*.ext` for files with an extension, else the path itself
+fn lfs_pattern(path: &str) -> String {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    match name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() && !ext.is_empty() => format!("*.{}", ext),
+        _ => path.to_string(),
+    }
+}
+
+fn quote_arg(arg: &str) -> String {
+    if !arg.is_empty()
+        && arg
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"-._/".contains(&b))
+    {
+        return arg.to_string();
+    }
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+/// Shell commands that move the flagged blobs to Git LFS
+pub(crate) fn remediation(report: &LargeFileReport) -> Vec<String> {
+    let mut commands = Vec::new();
+    if !lfs_available() {
+        commands.push("# install Git LFS (https://git-lfs.com), then: git lfs install".to_string());
+    }
+
+    let new_patterns: BTreeSet<String> = report.oversized.iter().map(|b| lfs_pattern(&b.path)).collect();
+    for pattern in &new_patterns {
+        commands.push(format!("git lfs track {}", quote_arg(pattern)));
+    }
+
+    let flagged = || report.oversized.iter().chain(&report.not_in_lfs);
+    let staged: BTreeSet<String> = flagged()
+        .filter(|b| b.origin == BlobOrigin::Index)
+        .map(|b| quote_arg(&b.path))
+        .collect();
+    if !staged.is_empty() {
+        let paths: Vec<String> = staged.into_iter().collect();
+        let attributes = if new_patterns.is_empty() { "" } else { ".gitattributes " };
+        commands.push(format!(
+            "git rm --cached -q -- {paths} && git add -- {attributes}{paths}",
+            paths = paths.join(" "),
+            attributes = attributes
+        ));
+    }
+
+    let committed: BTreeSet<String> = flagged()
+        .filter(|b| b.origin == BlobOrigin::Commit)
+        .map(|b| lfs_pattern(&b.path))
+        .collect();
+    if !committed.is_empty() {
+        let include = committed.into_iter().collect::<Vec<_>>().join(",");
+        // Without --everything, migrate only rewrites the current branch's unpushed commits
+        let scope = if report.all_refs { " --everything" } else { "" };
+        commands.push(format!("git lfs migrate import --include={}{}", quote_arg(&include), scope));
+    }
+    commands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blob(path: &str, size: u64, origin: BlobOrigin) -> LargeBlob {
+        LargeBlob {
+            path: path.to_string(),
+            oid: format!("oid-{}", path),
+            size,
+            origin,
+        }
+    }
+
+    #[test]
+    fn patterns_and_summary() {
+        assert_eq!(lfs_pattern("assets/video.mp4"), "*.mp4");
+        assert_eq!(lfs_pattern("data/.bashrc"), "data/.bashrc");
+        assert_eq!(lfs_pattern("bin/tool"), "bin/tool");
+        assert_eq!(quote_arg("*.psd"), "'*.psd'");
+        assert_eq!(quote_arg("docs/a b.pdf"), "'docs/a b.pdf'");
+
+        let report = LargeFileReport {
+            limit: 5 * 1024 * 1024,
+            blobs_checked: 4,
+            oversized: vec![blob("build/app.zip", 200 * 1024 * 1024, BlobOrigin::Commit)],
+            not_in_lfs: vec![blob("art/logo.psd", 1536 * 1024, BlobOrigin::Index)],
+            all_refs: false,
+        };
+        assert_eq!(
+            report.summary(),
+            "1 file(s) over 5.0 MiB: build/app.zip (200.0 MiB); \
+             1 LFS-tracked file(s) committed without LFS: art/logo.psd (1.5 MiB)"
+        );
+    }
+}
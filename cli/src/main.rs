@@ -1,12 +1,27 @@
 mod cli;
+mod branch_policy;
+mod commit_body;
 mod commit_cache;
+mod commit_notes;
+mod commit_trailers;
 mod commit_validator;
 mod config;
 mod diff_optimizer;
 mod flow;
 mod ai;
 mod git;
+mod gitleaks;
+mod hooks;
+mod issue_key;
+mod large_files;
 mod plan;
+mod preflight;
+mod prompts;
+mod redact;
+mod secret_baseline;
+mod secret_report;
+mod secret_rules;
+mod secret_scan;
 mod ui;
 mod api_client;
 mod plan_types;
@@ -23,6 +38,8 @@ fn pick_commit_style(default: Option<crate::cli::CommitStylePreset>) -> Result<O
         crate::cli::CommitStylePreset::ConventionalEmojis,
         crate::cli::CommitStylePreset::Gitmoji,
         crate::cli::CommitStylePreset::Jira,
+        crate::cli::CommitStylePreset::Linear,
+        crate::cli::CommitStylePreset::Github,
         crate::cli::CommitStylePreset::Simple,
     ];
 
@@ -66,7 +83,7 @@ fn pick_commit_style(default: Option<crate::cli::CommitStylePreset>) -> Result<O
 async fn main() {
     if let Err(e) = run().await {
         flows::print_friendly_error(&e);
-        std::process::exit(1);
+        std::process::exit(flows::exit_code(&e));
     }
 }
 
@@ -78,6 +95,9 @@ async fn run() -> Result<()> {
         return Ok(());
     }
 
+    crate::redact::set_enabled(!cli.no_redact);
+    crate::ai::guard::set_show_prompt(cli.show_prompt);
+
     let command = require_command(cli.command);
     dispatch_command(cli.yes, cli.yes_pr, command).await?;
 
@@ -112,12 +132,21 @@ async fn dispatch_command(
             style_pick,
             cache,
             regenerate,
+            signoff,
+            refs,
+            closes,
         } => {
+            let trailer_opts = crate::commit_trailers::TrailerOptions {
+                signoff,
+                refs,
+                closes,
+            };
             let resolved_style = if style_pick && style.is_none() {
                 pick_commit_style(style_preset)?
             } else {
                 style.or_else(|| style_preset.map(|p| p.instruction().to_string()))
             };
+            let model = crate::ai::models::resolve_model(crate::ai::models::ModelTask::Commit, model.as_deref());
             // Handle different modes of the commit command
             if plan_only {
                 // New way to do: orca commit --plan-only
@@ -133,11 +162,12 @@ async fn dispatch_command(
                     branch.as_deref(),
                     &base,
                     pr,
+                    &trailer_opts,
                 )
                 .await?
             } else {
                 // Regular commit flow
-                flows::run_commit_flow(confirm, dry_run, &model, resolved_style).await?
+                flows::run_commit_flow(confirm, dry_run, &model, resolved_style, &trailer_opts).await?
             }
         }
         crate::cli::Commands::Publish { branch, base, no_pr, mode, select, no_fetch } => {
@@ -155,15 +185,21 @@ async fn dispatch_command(
         crate::cli::Commands::Menu => flows::run_menu_flow().await?,
         crate::cli::Commands::Doctor => flows::run_doctor_flow().await?,
         crate::cli::Commands::Update => flows::run_update_flow().await?,
+        crate::cli::Commands::Cache(cache_cmd) => dispatch_cache_command(cache_cmd).await?,
+        crate::cli::Commands::Prompts(prompts_cmd) => dispatch_prompts_command(prompts_cmd).await?,
 
         crate::cli::Commands::Git(git_cmd) => dispatch_git_command(yes, git_cmd).await?,
         crate::cli::Commands::Branch(branch_cmd) => dispatch_branch_command(yes, branch_cmd).await?,
         crate::cli::Commands::Flow(flow_cmd) => dispatch_flow_command(yes, yes_pr, flow_cmd).await?,
         crate::cli::Commands::Tidy(tidy_cmd) => dispatch_tidy_command(yes, tidy_cmd).await?,
+        crate::cli::Commands::Pair(pair_cmd) => dispatch_pair_command(pair_cmd).await?,
+        crate::cli::Commands::Explain { commit } => flows::run_explain_flow(&commit).await?,
+        crate::cli::Commands::Notes(notes_cmd) => dispatch_notes_command(notes_cmd).await?,
         crate::cli::Commands::Conflict(conflict_cmd) => dispatch_conflict_command(yes, conflict_cmd).await?,
         crate::cli::Commands::Release(release_cmd) => dispatch_release_command(yes, release_cmd).await?,
         crate::cli::Commands::Stack(stack_cmd) => dispatch_stack_command(yes, yes_pr, stack_cmd).await?,
         crate::cli::Commands::Safe(safe_cmd) => dispatch_safe_command(safe_cmd).await?,
+        crate::cli::Commands::Hooks(hooks_cmd) => dispatch_hooks_command(hooks_cmd).await?,
 
         // ============ BACKWARD COMPATIBILITY (DEPRECATED) ============
         crate::cli::Commands::PublishCurrent { branch, base, no_pr, mode, select, no_fetch } => {
@@ -183,6 +219,7 @@ async fn dispatch_command(
         } => {
             eprintln!("⚠️  Warning: 'plan' is deprecated. Use 'commit --plan-only' instead.");
             eprintln!("   Example: orca commit --plan-only --out plan.json\n");
+            let model = crate::ai::models::resolve_model(crate::ai::models::ModelTask::Commit, model.as_deref());
             flows::run_plan_flow(&model, json_only, out, None, cache, regenerate).await?
         }
         crate::cli::Commands::Apply {
@@ -206,6 +243,7 @@ async fn dispatch_command(
                 branch.as_deref(),
                 &base,
                 pr,
+                &crate::commit_trailers::TrailerOptions::default(),
             )
             .await?
         }
@@ -223,8 +261,9 @@ async fn run_apply_flow(
     branch: Option<&str>,
     base: &str,
     pr: bool,
+    trailer_opts: &crate::commit_trailers::TrailerOptions,
 ) -> Result<()> {
-    flows::run_apply_flow(file, confirm, dry_run, push, publish, branch, base, pr).await
+    flows::run_apply_flow(file, confirm, dry_run, push, publish, branch, base, pr, trailer_opts).await
 }
 
 async fn dispatch_git_command(yes: bool, git_cmd: crate::cli::GitCommands) -> Result<()> {
@@ -288,6 +327,44 @@ async fn dispatch_tidy_command(yes: bool, tidy_cmd: crate::cli::TidyCommands) ->
     Ok(())
 }
 
+async fn dispatch_pair_command(pair_cmd: crate::cli::PairCommands) -> Result<()> {
+    match pair_cmd {
+        crate::cli::PairCommands::Add { name, email } => flows::run_pair_add_flow(&name, &email).await?,
+        crate::cli::PairCommands::Remove { who } => flows::run_pair_remove_flow(&who).await?,
+        crate::cli::PairCommands::List => flows::run_pair_list_flow().await?,
+        crate::cli::PairCommands::Clear => flows::run_pair_clear_flow().await?,
+    }
+    Ok(())
+}
+
+async fn dispatch_notes_command(notes_cmd: crate::cli::NotesCommands) -> Result<()> {
+    match notes_cmd {
+        crate::cli::NotesCommands::Push { remote } => flows::run_notes_push_flow(&remote).await?,
+        crate::cli::NotesCommands::Fetch { remote } => flows::run_notes_fetch_flow(&remote).await?,
+    }
+    Ok(())
+}
+
+async fn dispatch_cache_command(cache_cmd: crate::cli::CacheCommands) -> Result<()> {
+    match cache_cmd {
+        crate::cli::CacheCommands::List => flows::run_cache_list_flow().await?,
+        crate::cli::CacheCommands::Show { key } => flows::run_cache_show_flow(&key).await?,
+        crate::cli::CacheCommands::Clear => flows::run_cache_clear_flow().await?,
+        crate::cli::CacheCommands::Prune => flows::run_cache_prune_flow().await?,
+    }
+    Ok(())
+}
+
+async fn dispatch_prompts_command(prompts_cmd: crate::cli::PromptsCommands) -> Result<()> {
+    match prompts_cmd {
+        crate::cli::PromptsCommands::Show { name } => flows::run_prompts_show_flow(name.as_deref()).await?,
+        crate::cli::PromptsCommands::Init { global, force } => {
+            flows::run_prompts_init_flow(global, force).await?
+        }
+    }
+    Ok(())
+}
+
 async fn dispatch_conflict_command(
     yes: bool,
     conflict_cmd: crate::cli::ConflictCommands,
@@ -335,13 +412,49 @@ async fn dispatch_stack_command(
     Ok(())
 }
 
+async fn dispatch_hooks_command(hooks_cmd: crate::cli::HooksCommands) -> Result<()> {
+    match hooks_cmd {
+        crate::cli::HooksCommands::Install { hooks, ai_draft } => {
+            flows::run_hooks_install_flow(hooks, ai_draft).await?
+        }
+        crate::cli::HooksCommands::Uninstall => flows::run_hooks_uninstall_flow().await?,
+        crate::cli::HooksCommands::Status => flows::run_hooks_status_flow().await?,
+        crate::cli::HooksCommands::Run { hook, args } => flows::run_hook(hook, &args).await?,
+    }
+    Ok(())
+}
+
 async fn dispatch_safe_command(safe_cmd: crate::cli::SafeCommands) -> Result<()> {
     match safe_cmd {
-        crate::cli::SafeCommands::Scan { all } => flows::run_safe_scan_flow(all).await?,
+        crate::cli::SafeCommands::Scan {
+            all,
+            range,
+            history,
+            since,
+            min_confidence,
+            update_baseline,
+            large_files,
+            max_size,
+            format,
+        } => {
+            let target = match range {
+                None if history => crate::secret_scan::ScanTarget::History { since },
+                Some(range) => crate::secret_scan::ScanTarget::Range(range),
+                None if all => crate::secret_scan::ScanTarget::All,
+                None => crate::secret_scan::ScanTarget::Staged,
+            };
+            if large_files {
+                flows::run_safe_large_files_flow(target, max_size, format).await
+            } else {
+                flows::run_safe_scan_flow(target, min_confidence, update_baseline, format).await
+            }
+        }
         crate::cli::SafeCommands::Preflight {
             operation,
             protection,
-        } => flows::run_safe_preflight_flow(&operation, protection.as_deref()).await?,
+            format,
+        } => flows::run_safe_preflight_flow(&operation, protection.as_deref(), format).await,
     }
-    Ok(())
+    // Anything that isn't a findings result means the scan itself failed
+    .map_err(|e| flows::ExitCodeError::wrap(crate::secret_report::EXIT_SCAN_ERROR, e))
 }
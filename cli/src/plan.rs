@@ -1,3 +1,4 @@
+use crate::commit_body::render_commit_body;
 use crate::git::{patch_id_from_patch, recent_patch_ids, run_git};
 use anyhow::Result;
 use console::style;
@@ -73,37 +74,7 @@ pub(crate) fn print_plan_human(plan: &CommitPlan) {
         
         // Description
         if let Some(desc) = &c.description {
-            println!("\n  {}", style("📄 Description:").dim());
-            println!("  {}", style(&desc.summary).white());
-            
-            if !desc.changes.is_empty() {
-                println!();
-                for change in &desc.changes {
-                    println!("    {} {}", style("•").blue(), style(change).white());
-                }
-            }
-            
-            if let Some(impact) = &desc.impact {
-                println!();
-                let level_badge = match impact.level.to_lowercase().as_str() {
-                    "high" => style(" IMPACT: HIGH ").on_red().white().bold(),
-                    "medium" => style(" IMPACT: MEDIUM ").on_yellow().black().bold(),
-                    _ => style(" IMPACT: LOW ").on_green().white().bold(),
-                };
-                
-                println!("    {}  {}", level_badge, style(&impact.explanation).dim());
-                if !impact.affected_areas.is_empty() {
-                    println!("             {}: {}", style("Affected").dim(), style(impact.affected_areas.join(", ")).dim());
-                }
-            }
-            
-            if !desc.breaking_changes.is_empty() {
-                println!();
-                println!("    {}", style("🚨 BREAKING CHANGES").red().bold());
-                for bc in &desc.breaking_changes {
-                    println!("      {} {}", style("!").red(), style(bc).red());
-                }
-            }
+            print_description(desc);
         }
         
         // Files
@@ -125,6 +96,41 @@ pub(crate) fn print_plan_human(plan: &CommitPlan) {
     println!();
 }
 
+/// Print a commit description the way plans show it
+pub(crate) fn print_description(desc: &CommitDescription) {
+    println!("\n  {}", style("📄 Description:").dim());
+    println!("  {}", style(&desc.summary).white());
+
+    if !desc.changes.is_empty() {
+        println!();
+        for change in &desc.changes {
+            println!("    {} {}", style("•").blue(), style(change).white());
+        }
+    }
+
+    if let Some(impact) = &desc.impact {
+        println!();
+        let level_badge = match impact.level.to_lowercase().as_str() {
+            "high" => style(" IMPACT: HIGH ").on_red().white().bold(),
+            "medium" => style(" IMPACT: MEDIUM ").on_yellow().black().bold(),
+            _ => style(" IMPACT: LOW ").on_green().white().bold(),
+        };
+
+        println!("    {}  {}", level_badge, style(&impact.explanation).dim());
+        if !impact.affected_areas.is_empty() {
+            println!("             {}: {}", style("Affected").dim(), style(impact.affected_areas.join(", ")).dim());
+        }
+    }
+
+    if !desc.breaking_changes.is_empty() {
+        println!();
+        println!("    {}", style("🚨 BREAKING CHANGES").red().bold());
+        for bc in &desc.breaking_changes {
+            println!("      {} {}", style("!").red(), style(bc).red());
+        }
+    }
+}
+
 pub(crate) fn files_from_status_porcelain(status: &str) -> Vec<String> {
     let mut out = Vec::new();
     for line in status.lines() {
@@ -191,16 +197,31 @@ fn has_staged_changes() -> Result<bool> {
     Ok(!out.trim().is_empty())
 }
 
-pub(crate) fn apply_plan(plan: &mut CommitPlan, style_preset: Option<crate::cli::CommitStylePreset>) -> Result<()> {
+pub(crate) fn apply_plan(
+    plan: &mut CommitPlan,
+    style_preset: Option<crate::cli::CommitStylePreset>,
+    trailers: &[crate::commit_trailers::Trailer],
+) -> Result<()> {
     use crate::commit_validator::CommitMessageValidator;
+
+    crate::branch_policy::enforce_current(crate::branch_policy::ProtectedOperation::Commit)?;
     
     // Create validator based on style preset
     let validator = CommitMessageValidator::new(style_preset);
+    let issue_key = crate::issue_key::key_to_inject(style_preset);
+    let commit_config = crate::config::load_config()
+        .map(|c| c.commit)
+        .unwrap_or_default();
     
     // Sanitize and validate all commit messages first
     for (idx, c) in plan.commits.iter_mut().enumerate() {
         // Auto-sanitize message
         c.message = validator.auto_sanitize(&c.message);
+
+        // Add the issue key inferred from the branch name instead of trusting the model
+        if let Some(key) = &issue_key {
+            c.message = key.inject(&c.message);
+        }
         
         // Validate and show warnings (but don't block)
         let validation = validator.validate(&c.message);
@@ -248,55 +269,32 @@ pub(crate) fn apply_plan(plan: &mut CommitPlan, style_preset: Option<crate::cli:
         }
 
         // Tạo commit message với body (description)
-        if let Some(desc) = &c.description {
-            let commit_body = format_commit_body(desc);
-            let full_message = format!("{}\n\n{}", c.message, commit_body);
-            run_git(&["commit", "-m", &full_message])?;
-        } else {
-            run_git(&["commit", "-m", &c.message])?;
-        }
+        let commit_body = c
+            .description
+            .as_ref()
+            .and_then(|desc| render_commit_body(&commit_config, desc));
+        let full_message = match commit_body {
+            Some(body) => format!("{}\n\n{}", c.message, body),
+            None => c.message.clone(),
+        };
+        let full_message = crate::commit_trailers::apply_trailers(&full_message, trailers)?;
+        run_git(&["commit", "-m", &full_message])?;
 
         let new_hash = run_git(&["rev-parse", "HEAD"])?;
         let new_hash = new_hash.trim().to_string();
         if !new_hash.is_empty() {
+            // Keep the full description with the commit, even when it was left out of the body
+            if let Some(desc) = &c.description
+                && let Err(e) = crate::commit_notes::write_note(&new_hash, desc)
+            {
+                eprintln!("Warning: {}", e);
+            }
             c.hash = Some(new_hash);
         }
     }
     Ok(())
 }
 
-fn format_commit_body(desc: &CommitDescription) -> String {
-    let mut body = vec![];
-    
-    body.push(desc.summary.clone());
-    body.push(String::new());
-    
-    if !desc.changes.is_empty() {
-        body.push("Changes:".to_string());
-        for change in &desc.changes {
-            body.push(format!("- {}", change));
-        }
-        body.push(String::new());
-    }
-    
-    if let Some(impact) = &desc.impact {
-        body.push(format!("Impact: {} - {}", impact.level.to_uppercase(), impact.explanation));
-        if !impact.affected_areas.is_empty() {
-            body.push(format!("Affected areas: {}", impact.affected_areas.join(", ")));
-        }
-        body.push(String::new());
-    }
-    
-    if !desc.breaking_changes.is_empty() {
-        body.push("BREAKING CHANGES:".to_string());
-        for bc in &desc.breaking_changes {
-            body.push(format!("- {}", bc));
-        }
-    }
-    
-    body.join("\n")
-}
-
 #[cfg(test)]
 mod tests {
     use super::{files_from_status_porcelain, normalize_plan_files, CommitPlan, PlannedCommit};
@@ -0,0 +1,693 @@
+use crate::cli::CommitStylePreset;
+use crate::commit_validator::CommitMessageValidator;
+use crate::branch_policy::{self, ProtectedOperation, Verdict};
+use crate::config::{
+    PreflightCommand, PreflightProfile, PreflightSeverity, ProtectedBranchRule, ProtectionAction,
+};
+use crate::git::run_git;
+use crate::large_files;
+use crate::secret_rules::Confidence;
+use crate::secret_scan::{scan, ScanReport, ScanTarget};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Base branch when the profile does not name one
+const DEFAULT_BASE: &str = "main";
+
+const DEFAULT_COMMAND_TIMEOUT_SECS: u64 = 600;
+
+/// Lines of command output kept for a failed command
+const OUTPUT_TAIL_LINES: usize = 20;
+
+/// Subjects listed for a failed commit-lint before "and N more"
+const MAX_LISTED: usize = 5;
+
+/// Built-in preflight checks, in the order the ids appear in a profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Builtin {
+    Secrets,
+    BranchProtection,
+    Upstream,
+    LargeFiles,
+    CommitLint,
+    CleanTree,
+    BehindBase,
+}
+
+impl Builtin {
+    const ALL: [Builtin; 7] = [
+        Builtin::Secrets,
+        Builtin::BranchProtection,
+        Builtin::Upstream,
+        Builtin::LargeFiles,
+        Builtin::CommitLint,
+        Builtin::CleanTree,
+        Builtin::BehindBase,
+    ];
+
+    fn id(self) -> &'static str {
+        match self {
+            Builtin::Secrets => "secrets",
+            Builtin::BranchProtection => "branch-protection",
+            Builtin::Upstream => "upstream",
+            Builtin::LargeFiles => "large-files",
+            Builtin::CommitLint => "commit-lint",
+            Builtin::CleanTree => "clean-tree",
+            Builtin::BehindBase => "behind-base",
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Builtin::Secrets => "Scanning for secrets",
+            Builtin::BranchProtection => "Checking branch protection",
+            Builtin::Upstream => "Checking upstream configuration",
+            Builtin::LargeFiles => "Checking for large files",
+            Builtin::CommitLint => "Linting outgoing commit messages",
+            Builtin::CleanTree => "Checking for uncommitted changes",
+            Builtin::BehindBase => "Checking the branch is up to date with its base",
+        }
+    }
+
+    /// Advisory checks only fail the preflight when a profile raises them to "error"
+    fn default_severity(self) -> PreflightSeverity {
+        match self {
+            Builtin::Secrets | Builtin::LargeFiles | Builtin::CleanTree => PreflightSeverity::Error,
+            Builtin::BranchProtection | Builtin::Upstream | Builtin::CommitLint | Builtin::BehindBase => {
+                PreflightSeverity::Warning
+            }
+        }
+    }
+
+    fn parse(id: &str) -> Result<Builtin> {
+        Builtin::ALL.into_iter().find(|b| b.id() == id).with_context(|| {
+            let known: Vec<&str> = Builtin::ALL.iter().map(|b| b.id()).collect();
+            format!("Unknown preflight check '{}'; expected one of: {}", id, known.join(", "))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+/// Outcome of one preflight check
+#[derive(Debug, Serialize)]
+pub(crate) struct PreflightCheck {
+    pub(crate) name: String,
+    #[serde(skip)]
+    pub(crate) title: String,
+    pub(crate) status: CheckStatus,
+    pub(crate) severity: PreflightSeverity,
+    pub(crate) message: String,
+    /// Advice listed with the warnings in the summary
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) warning: Option<String>,
+    /// Last lines a failed command printed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) output: Option<String>,
+    pub(crate) duration_ms: u64,
+}
+
+impl PreflightCheck {
+    fn new(name: &str, title: &str, severity: PreflightSeverity, result: CheckResult, started: Instant) -> Self {
+        let status = match (result.ok, severity) {
+            (true, _) => CheckStatus::Pass,
+            (false, PreflightSeverity::Error) if !result.advisory => CheckStatus::Fail,
+            (false, _) => CheckStatus::Warn,
+        };
+        Self {
+            name: name.to_string(),
+            title: title.to_string(),
+            status,
+            severity,
+            message: result.message,
+            warning: result.warning,
+            output: result.output,
+            duration_ms: started.elapsed().as_millis() as u64,
+        }
+    }
+}
+
+/// What a check found, before severity turns it into a status
+struct CheckResult {
+    ok: bool,
+    /// Reported as a warning even for error-severity checks (e.g. the scan itself could not run)
+    advisory: bool,
+    message: String,
+    warning: Option<String>,
+    output: Option<String>,
+    /// Severity the finding itself calls for (a blocking branch rule); profile settings still win
+    severity: Option<PreflightSeverity>,
+}
+
+impl CheckResult {
+    fn pass(message: impl Into<String>) -> Self {
+        Self {
+            ok: true,
+            advisory: false,
+            message: message.into(),
+            warning: None,
+            output: None,
+            severity: None,
+        }
+    }
+
+    fn fail(message: impl Into<String>) -> Self {
+        Self {
+            ok: false,
+            ..Self::pass(message)
+        }
+    }
+
+    fn with_warning(mut self, warning: impl Into<String>) -> Self {
+        self.warning = Some(warning.into());
+        self
+    }
+}
+
+/// Profile for one `--operation`, resolved against the defaults and validated
+pub(crate) struct PreflightPlan {
+    pub(crate) operation: String,
+    /// Where the profile came from: a config file path, or None for the built-in default
+    pub(crate) source: Option<PathBuf>,
+    checks: Vec<Builtin>,
+    severity: BTreeMap<String, PreflightSeverity>,
+    protected_branch: Option<String>,
+    rules: Vec<ProtectedBranchRule>,
+    base: String,
+    max_file_bytes: Option<u64>,
+    parallel: usize,
+    commands: Vec<PreflightCommand>,
+}
+
+impl PreflightPlan {
+    /// `[preflight.<operation>]` from the merged configuration, else the default checks:
+    /// secrets, branch protection when any branch is protected, and upstream for pushes.
+    /// `protection` (from `--protection`) replaces the `[[protected_branches]]` rules
+    /// with a single branch that is warned about.
+    pub(crate) fn load(operation: &str, protection: Option<&str>) -> Result<Self> {
+        let layers = crate::config::load_layers()?;
+        let mut config = crate::config::merge_layers(&layers)?;
+        let source = layers
+            .iter()
+            .rev()
+            .find(|layer| layer.get(&["preflight", operation]).is_some())
+            .map(|layer| layer.path.clone());
+
+        let mut profile = config.preflight.remove(operation).unwrap_or_default();
+        profile.base = profile.base.or(config.git.base_branch);
+        Self::from_profile(operation, profile, source, protection, config.protected_branches)
+    }
+
+    fn from_profile(
+        operation: &str,
+        profile: PreflightProfile,
+        source: Option<PathBuf>,
+        protection: Option<&str>,
+        rules: Vec<ProtectedBranchRule>,
+    ) -> Result<Self> {
+        let checks = match &profile.checks {
+            Some(ids) => ids.iter().map(|id| Builtin::parse(id)).collect::<Result<Vec<_>>>()?,
+            None => {
+                let mut checks = vec![Builtin::Secrets];
+                if protection.is_some() || !rules.is_empty() {
+                    checks.push(Builtin::BranchProtection);
+                }
+                if operation == "push" {
+                    checks.push(Builtin::Upstream);
+                }
+                checks
+            }
+        };
+
+        for key in profile.severity.keys() {
+            let known = Builtin::parse(key).is_ok() || profile.commands.iter().any(|c| &c.name == key);
+            if !known {
+                anyhow::bail!("Unknown check '{}' in [preflight.{}] severity", key, operation);
+            }
+        }
+        let mut names = HashSet::new();
+        for command in &profile.commands {
+            if Builtin::parse(&command.name).is_ok() || !names.insert(command.name.as_str()) {
+                anyhow::bail!(
+                    "Command name '{}' in [preflight.{}] is already used by another check",
+                    command.name,
+                    operation
+                );
+            }
+        }
+
+        let base = profile.base.unwrap_or_else(|| DEFAULT_BASE.to_string());
+        Ok(Self {
+            operation: operation.to_string(),
+            source,
+            checks,
+            severity: profile.severity,
+            protected_branch: protection.map(str::to_string),
+            rules,
+            base,
+            max_file_bytes: profile.max_file_size_kb.map(|kb| kb * 1024),
+            parallel: profile.parallel.unwrap_or(1).max(1),
+            commands: profile.commands,
+        })
+    }
+
+    /// Profile setting, else what the result asks for, else the check's default
+    fn severity_of(&self, builtin: Builtin, requested: Option<PreflightSeverity>) -> PreflightSeverity {
+        self.severity
+            .get(builtin.id())
+            .copied()
+            .or(requested)
+            .unwrap_or_else(|| builtin.default_severity())
+    }
+
+    pub(crate) fn command_count(&self) -> usize {
+        self.commands.len()
+    }
+}
+
+/// Everything a preflight run produced
+pub(crate) struct PreflightRun {
+    pub(crate) checks: Vec<PreflightCheck>,
+    /// The staged secret scan, when the profile includes it
+    pub(crate) secret_scan: Option<Result<ScanReport>>,
+}
+
+impl PreflightRun {
+    pub(crate) fn passed(&self) -> bool {
+        self.checks.iter().all(|c| c.status != CheckStatus::Fail)
+    }
+}
+
+/// Run the built-in checks in order, then the commands `parallel` at a time
+pub(crate) fn run_preflight(plan: &PreflightPlan, repo_root: &Path) -> Result<PreflightRun> {
+    let mut checks = Vec::new();
+    let mut secret_scan = None;
+    let base_ref = crate::git::resolve_base_ref(&plan.base);
+
+    for &builtin in &plan.checks {
+        let started = Instant::now();
+        let result = match builtin {
+            Builtin::Secrets => {
+                let scanned = scan(&ScanTarget::Staged);
+                let result = secrets_result(&scanned);
+                secret_scan = Some(scanned);
+                result
+            }
+            Builtin::BranchProtection => branch_protection_result(plan)?,
+            Builtin::Upstream => upstream_result()?,
+            Builtin::LargeFiles => large_files_result(plan.max_file_bytes)?,
+            Builtin::CommitLint => commit_lint_result(&base_ref)?,
+            Builtin::CleanTree => clean_tree_result()?,
+            Builtin::BehindBase => behind_base_result(&base_ref)?,
+        };
+        checks.push(PreflightCheck::new(
+            builtin.id(),
+            builtin.title(),
+            plan.severity_of(builtin, result.severity),
+            result,
+            started,
+        ));
+    }
+
+    checks.extend(run_commands(&plan.commands, plan.parallel, &plan.severity, repo_root));
+    Ok(PreflightRun { checks, secret_scan })
+}
+
+fn secrets_result(scanned: &Result<ScanReport>) -> CheckResult {
+    match scanned {
+        Ok(report) => match report.issue_count(Confidence::Medium) {
+            0 => CheckResult::pass("No secrets detected"),
+            issues => CheckResult::fail(format!("{} potential secret(s) found", issues)),
+        },
+        Err(e) => CheckResult {
+            advisory: true,
+            ..CheckResult::fail("Secret scan could not run").with_warning(format!("Secret scan failed: {}", e))
+        },
+    }
+}
+
+fn branch_protection_result(plan: &PreflightPlan) -> Result<CheckResult> {
+    let current = crate::git::current_branch()?;
+    let verdict = match &plan.protected_branch {
+        Some(protected) => (&current == protected).then(|| Verdict {
+            action: ProtectionAction::Warn,
+            pattern: protected.clone(),
+        }),
+        None if plan.rules.is_empty() => return Ok(CheckResult::pass("No protected branch configured")),
+        None => branch_policy::evaluate(&plan.rules, &current, ProtectedOperation::Push),
+    };
+    let Some(verdict) = verdict else {
+        return Ok(CheckResult::pass(format!("'{}' is not a protected branch", current)));
+    };
+
+    let message = if plan.operation == "push" {
+        format!("Pushing directly to '{}'", current)
+    } else {
+        format!("Running {} from protected branch '{}'", plan.operation, current)
+    };
+    let mut result = CheckResult::fail(message).with_warning(format!(
+        "Consider using a feature branch and PR instead of pushing to '{}'",
+        current
+    ));
+    if verdict.action == ProtectionAction::Block {
+        result.severity = Some(PreflightSeverity::Error);
+        result.message = format!("{} (blocked by rule '{}')", result.message, verdict.pattern);
+    }
+    Ok(result)
+}
+
+fn upstream_result() -> Result<CheckResult> {
+    Ok(match crate::git::upstream_ref()? {
+        Some(upstream) => CheckResult::pass(format!("Upstream: {}", upstream)),
+        None => CheckResult::fail("No upstream configured").with_warning("Branch has no upstream. Will set with -u flag."),
+    })
+}
+
+/// Commits not yet published: those after the upstream if set, else after the merge base
+/// with `base_ref`; all of HEAD when neither exists
+fn outgoing_range(base_ref: &str) -> String {
+    let start = match crate::git::upstream_ref() {
+        Ok(Some(upstream)) => Some(upstream),
+        _ => crate::git::merge_base(base_ref, "HEAD").ok().filter(|b| !b.is_empty()),
+    };
+    match start {
+        Some(start) => format!("{}..HEAD", start),
+        None => "HEAD".to_string(),
+    }
+}
+
+fn large_files_result(max_file_bytes: Option<u64>) -> Result<CheckResult> {
+    let limit = match max_file_bytes {
+        Some(limit) => limit,
+        None => large_files::limit_bytes(&crate::config::load_config()?.large_files),
+    };
+    // Everything not on a remote yet, plus what the next commit adds
+    let report = large_files::scan_unpushed("HEAD", None, true, limit)?;
+    if report.issue_count() == 0 {
+        return Ok(CheckResult::pass(format!(
+            "{} outgoing blob(s), none over {} or missing from LFS",
+            report.blobs_checked,
+            large_files::human_size(limit)
+        )));
+    }
+    Ok(CheckResult {
+        output: Some(large_files::remediation(&report).join("\n")),
+        ..CheckResult::fail(report.summary()).with_warning("Large files bloat every clone; move them to Git LFS")
+    })
+}
+
+fn commit_lint_result(base_ref: &str) -> Result<CheckResult> {
+    let range = outgoing_range(base_ref);
+    let log = run_git(&["log", "--no-merges", "--format=%h%x09%s", &range])?;
+
+    // The range lint is about conventional commits, so that is the default style
+    let preset = crate::config::load_config()
+        .ok()
+        .and_then(|c| c.git.commit_style)
+        .as_deref()
+        .and_then(CommitStylePreset::from_style)
+        .or(Some(CommitStylePreset::Conventional));
+    let validator = CommitMessageValidator::new(preset);
+
+    let mut total = 0;
+    let mut problems = Vec::new();
+    for line in log.lines().filter(|l| !l.is_empty()) {
+        total += 1;
+        let (hash, subject) = line.split_once('\t').unwrap_or((line, ""));
+        let validation = validator.validate(subject);
+        if let Some(problem) = validation.errors.first().or(validation.warnings.first()) {
+            problems.push(format!("{} {}: {}", hash, subject, problem));
+        }
+    }
+
+    if problems.is_empty() {
+        return Ok(CheckResult::pass(format!("{} outgoing commit message(s) OK", total)));
+    }
+    let mut listed: Vec<String> = problems.iter().take(MAX_LISTED).cloned().collect();
+    if problems.len() > MAX_LISTED {
+        listed.push(format!("and {} more", problems.len() - MAX_LISTED));
+    }
+    Ok(CheckResult {
+        output: Some(listed.join("\n")),
+        ..CheckResult::fail(format!("{} of {} outgoing commit message(s) need attention", problems.len(), total))
+            .with_warning("Reword with `git rebase -i` or `orca tidy` before publishing")
+    })
+}
+
+fn clean_tree_result() -> Result<CheckResult> {
+    let status = run_git(&["status", "--porcelain", "--untracked-files=no"])?;
+    let changed = status.lines().filter(|l| !l.is_empty()).count();
+    Ok(match changed {
+        0 => CheckResult::pass("Working tree clean"),
+        n => CheckResult::fail(format!("{} file(s) with uncommitted changes", n))
+            .with_warning("Commit or stash local changes so the checks see what will be published"),
+    })
+}
+
+fn behind_base_result(base_ref: &str) -> Result<CheckResult> {
+    // Compared against the last fetch; preflight never touches the network
+    let Ok(count) = run_git(&["rev-list", "--count", &format!("HEAD..{}", base_ref)]) else {
+        return Ok(CheckResult::fail(format!("Base '{}' not found", base_ref))
+            .with_warning("Set `base` in the preflight profile or fetch the base branch"));
+    };
+    Ok(match count.trim().parse::<u64>().unwrap_or(0) {
+        0 => CheckResult::pass(format!("Up to date with {}", base_ref)),
+        n => CheckResult::fail(format!("{} commit(s) behind {}", n, base_ref))
+            .with_warning(format!("Rebase onto or merge {} before publishing", base_ref)),
+    })
+}
+
+/// Run `commands` on up to `parallel` threads; results keep the configured order
+fn run_commands(
+    commands: &[PreflightCommand],
+    parallel: usize,
+    severity: &BTreeMap<String, PreflightSeverity>,
+    repo_root: &Path,
+) -> Vec<PreflightCheck> {
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<PreflightCheck>>> = Mutex::new(commands.iter().map(|_| None).collect());
+
+    std::thread::scope(|scope| {
+        for _ in 0..parallel.min(commands.len()) {
+            scope.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::SeqCst);
+                    let Some(command) = commands.get(i) else {
+                        break;
+                    };
+                    let started = Instant::now();
+                    let result = run_command(command, repo_root);
+                    let severity = severity.get(&command.name).copied().unwrap_or(command.severity);
+                    let title = format!("Running {}", command.name);
+                    let check = PreflightCheck::new(&command.name, &title, severity, result, started);
+                    if let Ok(mut results) = results.lock() {
+                        results[i] = Some(check);
+                    }
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap_or_default()
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+fn shell(script: &str) -> Command {
+    if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", script]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", script]);
+        cmd
+    }
+}
+
+fn run_command(command: &PreflightCommand, repo_root: &Path) -> CheckResult {
+    let timeout = Duration::from_secs(command.timeout_secs.unwrap_or(DEFAULT_COMMAND_TIMEOUT_SECS));
+    let mut cmd = shell(&command.run);
+    cmd.current_dir(repo_root)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    // Own process group, so a timeout also stops whatever the shell started
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
+
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => return CheckResult::fail(format!("Could not start `{}`: {}", command.run, e)),
+    };
+
+    // stdout and stderr share one buffer so the tail reads like the terminal would
+    let output = Arc::new(Mutex::new(Vec::new()));
+    let readers: Vec<_> = [
+        child.stdout.take().map(|s| Box::new(s) as Box<dyn Read + Send>),
+        child.stderr.take().map(|s| Box::new(s) as Box<dyn Read + Send>),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|mut pipe| {
+        let output = Arc::clone(&output);
+        std::thread::spawn(move || {
+            let mut chunk = [0u8; 8192];
+            while let Ok(n) = pipe.read(&mut chunk) {
+                if n == 0 {
+                    break;
+                }
+                if let Ok(mut output) = output.lock() {
+                    output.extend_from_slice(&chunk[..n]);
+                }
+            }
+        })
+    })
+    .collect();
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if started.elapsed() >= timeout => {
+                kill_tree(&mut child);
+                break None;
+            }
+            Ok(None) => std::thread::sleep(Duration::from_millis(50)),
+            Err(e) => return CheckResult::fail(format!("Failed to wait for `{}`: {}", command.run, e)),
+        }
+    };
+    // After a timeout a stray grandchild may still hold the pipes; don't wait on it
+    if status.is_some() {
+        for reader in readers {
+            let _ = reader.join();
+        }
+    }
+    let tail = output.lock().map(|o| output_tail(&o)).unwrap_or_default();
+
+    let mut result = match status {
+        Some(status) if status.success() => CheckResult::pass(format!("`{}` succeeded", command.run)),
+        Some(status) => match status.code() {
+            Some(code) => CheckResult::fail(format!("`{}` exited with status {}", command.run, code)),
+            None => CheckResult::fail(format!("`{}` was terminated by a signal", command.run)),
+        },
+        None => CheckResult::fail(format!("`{}` timed out after {}s", command.run, timeout.as_secs())),
+    };
+    if !result.ok && !tail.is_empty() {
+        result.output = Some(tail);
+    }
+    result
+}
+
+fn kill_tree(child: &mut std::process::Child) {
+    #[cfg(unix)]
+    {
+        let _ = Command::new("kill")
+            .args(["-KILL", &format!("-{}", child.id())])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+fn output_tail(output: &[u8]) -> String {
+    let text = String::from_utf8_lossy(output);
+    let lines: Vec<&str> = text.trim_end().lines().collect();
+    lines[lines.len().saturating_sub(OUTPUT_TAIL_LINES)..].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(name: &str, run: &str, timeout_secs: Option<u64>) -> PreflightCommand {
+        PreflightCommand {
+            name: name.to_string(),
+            run: run.to_string(),
+            timeout_secs,
+            severity: PreflightSeverity::Error,
+        }
+    }
+
+    #[test]
+    fn profile_validation_and_defaults() {
+        let plan = PreflightPlan::from_profile("push", PreflightProfile::default(), None, None, vec![]).unwrap();
+        assert_eq!(plan.checks, vec![Builtin::Secrets, Builtin::Upstream]);
+        assert_eq!(plan.protected_branch, None);
+        let rules = vec![ProtectedBranchRule {
+            pattern: "main".to_string(),
+            push: ProtectionAction::Block,
+            force_push: ProtectionAction::Block,
+            commit: ProtectionAction::Warn,
+            rewrite: ProtectionAction::Block,
+        }];
+        let plan = PreflightPlan::from_profile("tag", PreflightProfile::default(), None, None, rules).unwrap();
+        assert_eq!(plan.checks, vec![Builtin::Secrets, Builtin::BranchProtection]);
+
+        let profile = PreflightProfile {
+            checks: Some(vec!["secrets".to_string(), "behind-base".to_string()]),
+            severity: BTreeMap::from([("behind-base".to_string(), PreflightSeverity::Error)]),
+            base: Some("develop".to_string()),
+            ..Default::default()
+        };
+        let plan = PreflightPlan::from_profile("release", profile, None, None, vec![]).unwrap();
+        assert_eq!(plan.severity_of(Builtin::BehindBase, None), PreflightSeverity::Error);
+        assert_eq!(plan.severity_of(Builtin::CommitLint, None), PreflightSeverity::Warning);
+        assert_eq!(
+            plan.severity_of(Builtin::BranchProtection, Some(PreflightSeverity::Error)),
+            PreflightSeverity::Error
+        );
+
+        let typo = PreflightProfile {
+            checks: Some(vec!["secret".to_string()]),
+            ..Default::default()
+        };
+        assert!(PreflightPlan::from_profile("release", typo, None, None, vec![]).is_err());
+        let clash = PreflightProfile {
+            commands: vec![command("secrets", "true", None)],
+            ..Default::default()
+        };
+        assert!(PreflightPlan::from_profile("release", clash, None, None, vec![]).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn commands_keep_order_and_report_failures() {
+        let commands = vec![
+            command("slow", "sleep 0.2; echo done", None),
+            command("lint", "echo 'src/a.rs:3 unused'; exit 3", None),
+            command("hang", "sleep 30", Some(1)),
+        ];
+        let severity = BTreeMap::from([("lint".to_string(), PreflightSeverity::Warning)]);
+        let checks = run_commands(&commands, 3, &severity, &std::env::temp_dir());
+
+        let names: Vec<&str> = checks.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["slow", "lint", "hang"]);
+        assert_eq!(checks[0].status, CheckStatus::Pass);
+        assert_eq!(checks[0].output, None);
+        assert_eq!(checks[1].status, CheckStatus::Warn);
+        assert_eq!(checks[1].output.as_deref(), Some("src/a.rs:3 unused"));
+        assert!(checks[1].message.contains("status 3"));
+        assert_eq!(checks[2].status, CheckStatus::Fail);
+        assert!(checks[2].message.contains("timed out"));
+        assert!(checks[2].duration_ms < 10_000);
+    }
+}
@@ -0,0 +1,317 @@
+use anyhow::{Context, Result};
+use regex::{Captures, Regex};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+
+/// Directory (under the repo root or the global config dir) holding prompt overrides
+const PROMPTS_DIR: &str = "prompts";
+
+const COMMIT_SYSTEM: &str = "<!-- prompt-version: 1 -->
+You are a senior software engineer. Your task is to propose a commit plan.
+";
+
+const COMMIT_USER: &str = r#"<!-- prompt-version: 1 -->
+Task: Propose a detailed commit plan for the current git working tree.
+    Rules:
+    - Output ONLY valid JSON. No markdown. No commentary.
+    - JSON schema: {"commits":[{"message":string,"files":[string],"commands":[string],"description":{"summary":string,"changes":[string],"impact":{"level":string,"explanation":string,"affected_areas":[string]},"breaking_changes":[string]}}]}
+    - Group files into logical commits by feature/responsibility.
+    - Commit messages should be concise, imperative, and conventional (e.g. feat:, fix:, refactor:, chore:).
+    - Commit Message Style: {style}
+    - For each commit, provide a detailed description:
+      * summary: 2-3 sentences describing what changed and why (in {language})
+      * changes: Bullet point list of the main changes
+      * impact: Assessment of impact level (low/medium/high) with explanation
+      * breaking_changes: List of breaking changes if any (empty array if none)
+    - Each file path must exist in git status output.
+    - For each commit, commands must contain EXACTLY 2 commands in this order:
+      1) git add -- <files...>
+      2) git commit -m "<message>"
+
+    Context:
+    GIT_STATUS_PORCELAIN:
+{status}
+
+    GIT_DIFF:
+{diff}
+
+    RECENT_GIT_LOG (for style):
+{log}
+"#;
+
+const RELEASE_SYSTEM: &str = "<!-- prompt-version: 1 -->
+You are a release manager writing release notes for end users.
+";
+
+const RELEASE_NOTES_USER: &str = "<!-- prompt-version: 1 -->
+Generate professional release notes from these commits. Group changes by category (Features, Bug Fixes, Improvements, etc.). Make it concise and user-friendly.
+
+Commits:
+{log}
+";
+
+const RELEASE_CREATE_USER: &str = "<!-- prompt-version: 1 -->
+Generate professional release notes for version {version}. Group changes by category. Make it concise.
+
+Commits:
+{log}
+";
+
+/// AI task with its own prompt pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PromptKind {
+    Commit,
+    ReleaseNotes,
+    ReleaseCreate,
+}
+
+impl PromptKind {
+    pub(crate) const ALL: [PromptKind; 3] = [
+        PromptKind::Commit,
+        PromptKind::ReleaseNotes,
+        PromptKind::ReleaseCreate,
+    ];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            PromptKind::Commit => "commit",
+            PromptKind::ReleaseNotes => "release-notes",
+            PromptKind::ReleaseCreate => "release-create",
+        }
+    }
+
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.name() == name.trim())
+    }
+
+    /// Placeholders the command fills in, for `orca prompts show`
+    pub(crate) fn placeholders(self) -> &'static [&'static str] {
+        match self {
+            PromptKind::Commit => &["status", "diff", "log", "style", "language"],
+            PromptKind::ReleaseNotes => &["log"],
+            PromptKind::ReleaseCreate => &["version", "log"],
+        }
+    }
+
+    fn defaults(self) -> (&'static str, &'static str) {
+        match self {
+            PromptKind::Commit => (COMMIT_SYSTEM, COMMIT_USER),
+            PromptKind::ReleaseNotes => (RELEASE_SYSTEM, RELEASE_NOTES_USER),
+            PromptKind::ReleaseCreate => (RELEASE_SYSTEM, RELEASE_CREATE_USER),
+        }
+    }
+
+    fn file_names(self) -> (String, String) {
+        (format!("{}.system.md", self.name()), format!("{}.md", self.name()))
+    }
+}
+
+/// Where a template came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TemplateSource {
+    Repo(PathBuf),
+    Global(PathBuf),
+    BuiltIn,
+}
+
+impl std::fmt::Display for TemplateSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateSource::Repo(p) | TemplateSource::Global(p) => write!(f, "{}", p.display()),
+            TemplateSource::BuiltIn => write!(f, "built-in"),
+        }
+    }
+}
+
+pub(crate) struct Template {
+    pub(crate) text: String,
+    pub(crate) source: TemplateSource,
+}
+
+/// System and user templates for one command
+pub(crate) struct PromptTemplates {
+    pub(crate) system: Template,
+    pub(crate) user: Template,
+}
+
+impl PromptTemplates {
+    /// Resolve templates: repo `.orca/prompts/`, then `<config dir>/orca/prompts/`, then built-in
+    pub(crate) fn load(kind: PromptKind) -> Self {
+        let (system_default, user_default) = kind.defaults();
+        let (system_file, user_file) = kind.file_names();
+        Self {
+            system: load_template(&system_file, system_default),
+            user: load_template(&user_file, user_default),
+        }
+    }
+
+    /// The built-in templates, ignoring any overrides on disk
+    pub(crate) fn builtin(kind: PromptKind) -> Self {
+        let (system, user) = kind.defaults();
+        let template = |text: &str| Template {
+            text: text.to_string(),
+            source: TemplateSource::BuiltIn,
+        };
+        Self {
+            system: template(system),
+            user: template(user),
+        }
+    }
+
+    /// Render both prompts, returning (system, user)
+    pub(crate) fn render(&self, vars: &[(&str, &str)]) -> (String, String) {
+        (render(&self.system.text, vars), render(&self.user.text, vars))
+    }
+
+    /// Identifies the prompt text for cache keys: the version markers plus a content hash,
+    /// so editing a template invalidates cached plans even without bumping the marker
+    pub(crate) fn cache_version(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.system.text.as_bytes());
+        hasher.update([0u8]);
+        hasher.update(self.user.text.as_bytes());
+        let hash = format!("{:x}", hasher.finalize());
+
+        format!(
+            "{}/{}+{}",
+            version_marker(&self.system.text).unwrap_or("none"),
+            version_marker(&self.user.text).unwrap_or("none"),
+            &hash[..12]
+        )
+    }
+}
+
+fn repo_prompts_dir() -> Option<PathBuf> {
+    crate::git::get_repo_root()
+        .ok()
+        .map(|root| root.join(".orca").join(PROMPTS_DIR))
+}
+
+pub(crate) fn global_prompts_dir() -> Result<PathBuf> {
+    let config_file = crate::config::config_file_path()?;
+    Ok(config_file.with_file_name(PROMPTS_DIR))
+}
+
+fn load_template(file_name: &str, default: &str) -> Template {
+    if let Some(path) = repo_prompts_dir().map(|d| d.join(file_name))
+        && let Ok(text) = std::fs::read_to_string(&path)
+    {
+        return Template {
+            text,
+            source: TemplateSource::Repo(path),
+        };
+    }
+
+    if let Some(path) = global_prompts_dir().ok().map(|d| d.join(file_name))
+        && let Ok(text) = std::fs::read_to_string(&path)
+    {
+        return Template {
+            text,
+            source: TemplateSource::Global(path),
+        };
+    }
+
+    Template {
+        text: default.to_string(),
+        source: TemplateSource::BuiltIn,
+    }
+}
+
+/// Write the built-in templates into `dir`; existing files are kept unless `force`.
+/// Returns the files written.
+pub(crate) fn write_defaults(dir: &std::path::Path, force: bool) -> Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    let mut written = Vec::new();
+    for kind in PromptKind::ALL {
+        let builtin = PromptTemplates::builtin(kind);
+        let (system_file, user_file) = kind.file_names();
+        for (file_name, template) in [(system_file, builtin.system), (user_file, builtin.user)] {
+            let path = dir.join(file_name);
+            if path.exists() && !force {
+                continue;
+            }
+            std::fs::write(&path, template.text)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            written.push(path);
+        }
+    }
+    Ok(written)
+}
+
+/// `<!-- prompt-version: X -->` marker, if present
+fn version_marker(text: &str) -> Option<&str> {
+    text.lines()
+        .map(str::trim)
+        .find_map(|l| l.strip_prefix("<!-- prompt-version:")?.strip_suffix("-->"))
+        .map(str::trim)
+}
+
+/// Substitute `{name}` placeholders in a single pass (so braces inside the diff are never
+/// re-expanded) and drop the version marker line. Unknown placeholders are left as-is.
+fn render(template: &str, vars: &[(&str, &str)]) -> String {
+    let body: String = template
+        .lines()
+        .filter(|l| !l.trim_start().starts_with("<!-- prompt-version:"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let Ok(re) = Regex::new(r"\{([a-z_]+)\}") else {
+        return body;
+    };
+    re.replace_all(&body, |caps: &Captures| {
+        vars.iter()
+            .find(|(name, _)| *name == &caps[1])
+            .map(|(_, value)| value.to_string())
+            .unwrap_or_else(|| caps[0].to_string())
+    })
+    .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_placeholders_once_and_keeps_json_braces() {
+        let (_, user) = PromptKind::Commit.defaults();
+        let out = render(
+            user,
+            &[
+                ("status", " M a.rs"),
+                ("diff", "+let s = \"{log}\";"),
+                ("log", "abc init"),
+                ("style", "Conventional"),
+                ("language", "English"),
+            ],
+        );
+        assert!(!out.contains("prompt-version"));
+        assert!(out.contains("+let s = \"{log}\";"));
+        assert!(out.contains("RECENT_GIT_LOG (for style):\nabc init"));
+        assert!(out.contains(r#"{"commits":[{"message":string"#));
+        assert!(out.contains("(in English)"));
+    }
+
+    #[test]
+    fn cache_version_tracks_marker_and_content() {
+        let templates = |user: &str| {
+            let mut t = PromptTemplates::builtin(PromptKind::Commit);
+            t.user.text = user.to_string();
+            t
+        };
+
+        let builtin = PromptTemplates::builtin(PromptKind::Commit).cache_version();
+        assert!(builtin.starts_with("1/1+"));
+        assert_ne!(builtin, templates("<!-- prompt-version: 1 -->\nHouse rules").cache_version());
+        assert!(templates("No marker").cache_version().starts_with("1/none+"));
+    }
+
+    #[test]
+    fn prompt_kinds_roundtrip_names() {
+        for kind in PromptKind::ALL {
+            assert_eq!(PromptKind::from_name(kind.name()), Some(kind));
+        }
+        assert_eq!(PromptKind::from_name("review"), None);
+    }
+}
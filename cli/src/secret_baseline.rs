@@ -0,0 +1,81 @@
+use crate::secret_scan::Finding;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+/// Accepted findings, committed at the repository root
+pub(crate) const BASELINE_FILE: &str = ".orca-secrets-baseline.json";
+
+const BASELINE_VERSION: u32 = 1;
+
+/// Findings the team has reviewed and accepted; later scans only report new ones
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct Baseline {
+    pub(crate) version: u32,
+    pub(crate) findings: Vec<BaselineEntry>,
+}
+
+/// One accepted finding. Only a hash of the secret is stored, never the value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct BaselineEntry {
+    pub(crate) fingerprint: String,
+    pub(crate) rule_id: String,
+    pub(crate) file: String,
+    /// Informational; matching uses the fingerprint, so moved lines stay accepted
+    pub(crate) line_number: usize,
+}
+
+impl Baseline {
+    pub(crate) fn from_findings(findings: &[Finding]) -> Self {
+        let mut entries: Vec<BaselineEntry> = findings
+            .iter()
+            .map(|f| BaselineEntry {
+                fingerprint: f.fingerprint.clone(),
+                rule_id: f.rule_id.clone(),
+                file: f.file.clone(),
+                line_number: f.line_number,
+            })
+            .collect();
+        // Stable order keeps diffs of the committed file small
+        entries.sort_by(|a, b| {
+            (&a.file, a.line_number, &a.rule_id).cmp(&(&b.file, b.line_number, &b.rule_id))
+        });
+        let mut seen = HashSet::new();
+        entries.retain(|e| seen.insert(e.fingerprint.clone()));
+
+        Self {
+            version: BASELINE_VERSION,
+            findings: entries,
+        }
+    }
+
+    pub(crate) fn fingerprints(&self) -> HashSet<&str> {
+        self.findings.iter().map(|e| e.fingerprint.as_str()).collect()
+    }
+}
+
+pub(crate) fn baseline_path() -> Result<PathBuf> {
+    Ok(crate::git::get_repo_root()?.join(BASELINE_FILE))
+}
+
+/// The repository's baseline, if one has been recorded
+pub(crate) fn load_baseline() -> Result<Option<Baseline>> {
+    let path = baseline_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let baseline = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(Some(baseline))
+}
+
+pub(crate) fn write_baseline(baseline: &Baseline) -> Result<PathBuf> {
+    let path = baseline_path()?;
+    let json = serde_json::to_string_pretty(baseline)?;
+    std::fs::write(&path, json + "\n")
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
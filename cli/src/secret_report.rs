@@ -0,0 +1,295 @@
+use crate::secret_rules::Confidence;
+use crate::secret_scan::{Finding, ScanReport, ScanTarget};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// Exit status when a scan completed and found blocking issues
+pub(crate) const EXIT_FINDINGS: i32 = 1;
+/// Exit status when `orca safe` could not complete (bad revision, git failure, invalid config)
+pub(crate) const EXIT_SCAN_ERROR: i32 = 2;
+
+/// Bumped when a field is removed or changes meaning; new fields may be added freely
+const JSON_SCHEMA_VERSION: u32 = 1;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF rule for files flagged by name rather than content
+const RISKY_FILE_RULE: &str = "risky-file";
+
+// Machine-readable reports never include the matched line: they end up in CI
+// artifacts and dashboards, which must not become a second copy of the secret.
+
+#[derive(Serialize)]
+pub(crate) struct JsonReport<'a> {
+    schema_version: u32,
+    tool: &'static str,
+    tool_version: &'static str,
+    target: String,
+    min_confidence: &'static str,
+    rule_sources: &'a [String],
+    passed: bool,
+    summary: JsonSummary,
+    findings: Vec<JsonFinding<'a>>,
+    risky_files: &'a [String],
+}
+
+#[derive(Serialize)]
+struct JsonSummary {
+    files_scanned: usize,
+    findings: usize,
+    blocking: usize,
+    risky_files: usize,
+    allowlisted: usize,
+    baselined: usize,
+}
+
+#[derive(Serialize)]
+struct JsonFinding<'a> {
+    rule_id: &'a str,
+    name: &'a str,
+    confidence: &'static str,
+    blocking: bool,
+    file: &'a str,
+    line: usize,
+    fingerprint: &'a str,
+    commit: Option<JsonCommit<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonCommit<'a> {
+    hash: &'a str,
+    author: &'a str,
+    date: &'a str,
+}
+
+pub(crate) fn json_report<'a>(
+    report: &'a ScanReport,
+    target: &ScanTarget,
+    min_confidence: Confidence,
+) -> JsonReport<'a> {
+    let findings: Vec<JsonFinding> = report
+        .findings
+        .iter()
+        .map(|f| JsonFinding {
+            rule_id: &f.rule_id,
+            name: &f.pattern_name,
+            confidence: f.confidence.as_str(),
+            blocking: f.confidence >= min_confidence,
+            file: &f.file,
+            line: f.line_number,
+            fingerprint: &f.fingerprint,
+            commit: f.commit.as_ref().map(|c| JsonCommit {
+                hash: &c.hash,
+                author: &c.author,
+                date: &c.date,
+            }),
+        })
+        .collect();
+
+    JsonReport {
+        schema_version: JSON_SCHEMA_VERSION,
+        tool: "orca",
+        tool_version: env!("CARGO_PKG_VERSION"),
+        target: target.describe(),
+        min_confidence: min_confidence.as_str(),
+        rule_sources: &report.rule_sources,
+        passed: report.issue_count(min_confidence) == 0,
+        summary: JsonSummary {
+            files_scanned: report.files.len(),
+            findings: report.findings.len(),
+            blocking: report.blocking(min_confidence).count(),
+            risky_files: report.risky_files.len(),
+            allowlisted: report.allowlisted,
+            baselined: report.baselined,
+        },
+        findings,
+        risky_files: &report.risky_files,
+    }
+}
+
+/// SARIF 2.1.0 log with one run. `error` marks a scan that could not complete,
+/// so dashboards don't read an empty result list as "clean".
+pub(crate) fn sarif_report(report: &ScanReport, min_confidence: Confidence, error: Option<&str>) -> Value {
+    let mut rules: Vec<Value> = Vec::new();
+    let mut rule_ids: Vec<String> = Vec::new();
+    let mut rule_index = |id: &str, name: &str| -> usize {
+        if let Some(i) = rule_ids.iter().position(|r| r == id) {
+            return i;
+        }
+        rules.push(json!({
+            "id": id,
+            "name": name,
+            "shortDescription": { "text": name },
+        }));
+        rule_ids.push(id.to_string());
+        rule_ids.len() - 1
+    };
+
+    let mut results: Vec<Value> = report
+        .findings
+        .iter()
+        .map(|f| finding_result(f, rule_index(&f.rule_id, &f.pattern_name), min_confidence))
+        .collect();
+    for file in &report.risky_files {
+        let index = rule_index(RISKY_FILE_RULE, "Risky File");
+        results.push(json!({
+            "ruleId": RISKY_FILE_RULE,
+            "ruleIndex": index,
+            "level": "error",
+            "message": { "text": format!("{} should not be committed", file) },
+            "locations": [location(file, None)],
+        }));
+    }
+
+    let mut invocation = json!({ "executionSuccessful": error.is_none() });
+    if let Some(error) = error {
+        invocation["toolExecutionNotifications"] = json!([{
+            "level": "error",
+            "message": { "text": error },
+        }]);
+    }
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "orca",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "invocations": [invocation],
+            "results": results,
+        }]
+    })
+}
+
+fn finding_result(finding: &Finding, rule_index: usize, min_confidence: Confidence) -> Value {
+    let level = if finding.confidence >= min_confidence {
+        "error"
+    } else {
+        "note"
+    };
+    let mut result = json!({
+        "ruleId": finding.rule_id,
+        "ruleIndex": rule_index,
+        "level": level,
+        "message": {
+            "text": format!("{} ({} confidence)", finding.pattern_name, finding.confidence.as_str())
+        },
+        "locations": [location(&finding.file, Some(finding.line_number))],
+        "partialFingerprints": { "orcaSecret/v1": finding.fingerprint },
+        "properties": { "confidence": finding.confidence.as_str() },
+    });
+    if let Some(commit) = &finding.commit {
+        result["properties"]["commit"] = json!(commit.hash);
+        result["properties"]["author"] = json!(commit.author);
+        result["properties"]["date"] = json!(commit.date);
+    }
+    result
+}
+
+fn location(file: &str, line: Option<usize>) -> Value {
+    let mut physical = json!({
+        "artifactLocation": { "uri": file_uri(file), "uriBaseId": "%SRCROOT%" },
+    });
+    // SARIF lines are 1-based; 0 would make the whole log invalid
+    if let Some(line) = line.filter(|l| *l > 0) {
+        physical["region"] = json!({ "startLine": line });
+    }
+    json!({ "physicalLocation": physical })
+}
+
+/// Relative URI reference for a repository path (`docs/my notes.md` -> `docs/my%20notes.md`)
+fn file_uri(path: &str) -> String {
+    let mut uri = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    uri
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secret_scan::CommitInfo;
+
+    fn finding(rule_id: &str, file: &str, line_number: usize, confidence: Confidence) -> Finding {
+        Finding {
+            file: file.to_string(),
+            line_number,
+            rule_id: rule_id.to_string(),
+            pattern_name: rule_id.to_string(),
+            confidence,
+            context: "token = ghp_secretvalue".to_string(),
+            fingerprint: format!("fp-{}-{}", file, line_number),
+            commit: None,
+        }
+    }
+
+    fn sample_report() -> ScanReport {
+        let mut committed = finding("github-token", "src/a b.rs", 3, Confidence::High);
+        committed.commit = Some(CommitInfo {
+            hash: "abc123".to_string(),
+            author: "Jane <jane@example.com>".to_string(),
+            date: "2024-05-01".to_string(),
+        });
+        ScanReport {
+            files: vec!["src/a b.rs".to_string(), ".env".to_string()],
+            risky_files: vec![".env".to_string()],
+            findings: vec![
+                committed,
+                finding("github-token", "src/c.rs", 9, Confidence::High),
+                finding("password-assignment", "src/c.rs", 10, Confidence::Low),
+            ],
+            rule_sources: vec!["built-in".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sarif_has_rules_locations_and_fingerprints() {
+        let sarif = sarif_report(&sample_report(), Confidence::Medium, None);
+        let run = &sarif["runs"][0];
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(run["invocations"][0]["executionSuccessful"], true);
+
+        let rules: Vec<&str> = run["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| r["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(rules, vec!["github-token", "password-assignment", "risky-file"]);
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 4);
+        let first = &results[0];
+        assert_eq!(first["ruleIndex"], 0);
+        assert_eq!(first["level"], "error");
+        assert_eq!(first["partialFingerprints"]["orcaSecret/v1"], "fp-src/a b.rs-3");
+        let location = &first["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/a%20b.rs");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(first["properties"]["commit"], "abc123");
+        assert_eq!(results[2]["level"], "note");
+        assert!(results[3]["locations"][0]["physicalLocation"].get("region").is_none());
+    }
+
+    #[test]
+    fn json_report_never_contains_the_matched_line() {
+        let report = sample_report();
+        let json = serde_json::to_value(json_report(&report, &ScanTarget::Staged, Confidence::Medium)).unwrap();
+        assert_eq!(json["passed"], false);
+        assert_eq!(json["summary"]["blocking"], 2);
+        assert_eq!(json["findings"][2]["blocking"], false);
+        assert_eq!(json["findings"][0]["commit"]["hash"], "abc123");
+        assert!(!json.to_string().contains("ghp_secretvalue"));
+    }
+}
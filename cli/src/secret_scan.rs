@@ -0,0 +1,736 @@
+use crate::config::SecretsConfig;
+use crate::git::{for_each_git_line, run_git};
+use crate::gitleaks::{self, GitleaksRules, GITLEAKS_INLINE_ALLOW};
+use crate::secret_rules::{confidence_for, find_secrets, Confidence};
+use anyhow::Result;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+// Risky file patterns
+const RISKY_FILES: &[&str] = &[
+    ".env",
+    ".env.local",
+    ".env.development",
+    ".env.production",
+    ".env.test",
+    "*.pem",
+    "*.key",
+    "*.p12",
+    "*.pfx",
+    "id_rsa",
+    "id_dsa",
+    "id_ecdsa",
+    "id_ed25519",
+    "*.kdb",
+    "*.kdbx",
+    "credentials.json",
+    "service-account.json",
+];
+
+/// Lines containing this marker (usually in a comment) are never reported
+pub(crate) const INLINE_ALLOW: &str = "orca:allow-secret";
+
+/// Marks the start of each commit in `git log -p` output (cannot start a patch line)
+const COMMIT_MARKER: char = '\u{1}';
+
+/// Diff options shared by every scan: added lines only, stable `a/` `b/` prefixes
+/// regardless of user config, no external diff or textconv drivers
+const PATCH_ARGS: &[&str] = &[
+    "-U0",
+    "--no-color",
+    "--no-ext-diff",
+    "--no-textconv",
+    "--src-prefix=a/",
+    "--dst-prefix=b/",
+];
+
+/// What to scan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ScanTarget {
+    /// Lines added in the index, i.e. exactly what the next commit will contain
+    Staged,
+    /// Every tracked file as stored in the index
+    All,
+    /// Lines added by each commit in a range such as `origin/main..HEAD`
+    Range(String),
+    /// Lines added by every commit reachable from any ref, optionally only those after `since`
+    History { since: Option<String> },
+}
+
+impl ScanTarget {
+    /// Short label for output, e.g. `staged` or `origin/main..HEAD`
+    pub(crate) fn describe(&self) -> String {
+        match self {
+            ScanTarget::Staged => "staged".to_string(),
+            ScanTarget::All => "tracked".to_string(),
+            ScanTarget::Range(range) => range.clone(),
+            ScanTarget::History { since: None } => "full history".to_string(),
+            ScanTarget::History { since: Some(since) } => format!("history since {}", since),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Finding {
+    pub(crate) file: String,
+    pub(crate) line_number: usize,
+    pub(crate) rule_id: String,
+    pub(crate) pattern_name: String,
+    pub(crate) confidence: Confidence,
+    pub(crate) context: String,
+    /// Hash of rule, file and secret value; identifies the finding in the baseline
+    pub(crate) fingerprint: String,
+    /// Commit that introduced the line (range and history scans only)
+    pub(crate) commit: Option<CommitInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CommitInfo {
+    pub(crate) hash: String,
+    pub(crate) author: String,
+    pub(crate) date: String,
+}
+
+impl CommitInfo {
+    pub(crate) fn short_hash(&self) -> &str {
+        &self.hash[..self.hash.len().min(8)]
+    }
+
+    /// Parse the `%H%x09%an <%ae>%x09%as` text following a commit marker
+    fn parse(raw: &str) -> Self {
+        let mut parts = raw.trim().splitn(3, '\t');
+        Self {
+            hash: parts.next().unwrap_or_default().to_string(),
+            author: parts.next().unwrap_or_default().to_string(),
+            date: parts.next().unwrap_or_default().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ScanReport {
+    pub(crate) files: Vec<String>,
+    pub(crate) risky_files: Vec<String>,
+    pub(crate) findings: Vec<Finding>,
+    /// Findings and risky files dropped by `[secrets]` allow_paths / allow_rules
+    pub(crate) allowlisted: usize,
+    /// Findings already recorded in the baseline file
+    pub(crate) baselined: usize,
+    /// Rule sets that ran, e.g. `built-in` and `.gitleaks.toml`
+    pub(crate) rule_sources: Vec<String>,
+}
+
+impl ScanReport {
+    /// Risky files plus findings at or above `min_confidence`
+    pub(crate) fn issue_count(&self, min_confidence: Confidence) -> usize {
+        self.risky_files.len() + self.blocking(min_confidence).count()
+    }
+
+    pub(crate) fn blocking(&self, min_confidence: Confidence) -> impl Iterator<Item = &Finding> {
+        self.findings.iter().filter(move |f| f.confidence >= min_confidence)
+    }
+}
+
+/// Runs the secret rules over added lines of patch output
+pub(crate) struct Scanner {
+    /// orca's own rules and risky-file list; off when a gitleaks config replaces them
+    builtin: bool,
+    gitleaks: Option<GitleaksRules>,
+}
+
+impl Scanner {
+    pub(crate) fn new() -> Self {
+        Self {
+            builtin: true,
+            gitleaks: None,
+        }
+    }
+
+    /// Built-in rules plus the repository's gitleaks config, if any. Without
+    /// `[extend] useDefault = true` the gitleaks rules replace the built-in ones,
+    /// so results match what gitleaks reports in CI.
+    fn for_repo(repo_root: &Path, settings: &SecretsConfig) -> Result<Self> {
+        let Some(path) = gitleaks::find_config(repo_root, settings.gitleaks_config.as_deref()) else {
+            return Ok(Self::new());
+        };
+        let rules = GitleaksRules::load(&path)?;
+        Ok(Self {
+            builtin: rules.use_default,
+            gitleaks: Some(rules),
+        })
+    }
+
+    fn rule_sources(&self) -> Vec<String> {
+        let mut sources = Vec::new();
+        if self.builtin {
+            sources.push("built-in".to_string());
+        }
+        if let Some(gitleaks) = &self.gitleaks {
+            sources.push(gitleaks.source.clone());
+        }
+        sources
+    }
+
+    fn is_risky(&self, file: &str) -> bool {
+        if let Some(gitleaks) = &self.gitleaks {
+            if gitleaks.flags_path(file) {
+                return true;
+            }
+            if gitleaks.allows_path(file) {
+                return false;
+            }
+        }
+        self.builtin && is_risky_file(file)
+    }
+
+    /// Feed one line of patch output, collecting findings for added lines
+    fn scan_line(&self, walker: &mut PatchWalker, line: &str, findings: &mut Vec<Finding>) {
+        let Some(added) = walker.feed(line) else {
+            return;
+        };
+        if added.text.contains(INLINE_ALLOW) {
+            return;
+        }
+        let new_finding = |rule_id: &str, name: &str, confidence, start: usize, end: usize| Finding {
+            file: added.file.to_string(),
+            line_number: added.line_number,
+            rule_id: rule_id.to_string(),
+            pattern_name: name.to_string(),
+            confidence,
+            context: added.text.trim().to_string(),
+            fingerprint: fingerprint(rule_id, added.file, &added.text[start..end]),
+            commit: added.commit.cloned(),
+        };
+
+        // gitleaks matches are reported as-is (CI fails on them); built-in
+        // matches of the same value are dropped
+        let mut taken: Vec<(usize, usize)> = Vec::new();
+        if let Some(gitleaks) = &self.gitleaks {
+            if added.text.contains(GITLEAKS_INLINE_ALLOW) {
+                return;
+            }
+            let commit = added.commit.map(|c| c.hash.as_str());
+            for found in gitleaks.find(added.text, added.file, commit) {
+                taken.push((found.start, found.end));
+                findings.push(new_finding(
+                    found.rule_id,
+                    found.description,
+                    Confidence::High,
+                    found.start,
+                    found.end,
+                ));
+            }
+        }
+
+        if !self.builtin {
+            return;
+        }
+        for found in find_secrets(added.text) {
+            let overlaps = taken.iter().any(|&(start, end)| found.start < end && start < found.end);
+            let disabled = self.gitleaks.as_ref().is_some_and(|g| g.is_disabled(found.rule.id));
+            if overlaps || disabled {
+                continue;
+            }
+            findings.push(new_finding(
+                found.rule.id,
+                found.rule.name,
+                confidence_for(found.rule, added.file),
+                found.start,
+                found.end,
+            ));
+        }
+    }
+
+    /// Scan the added lines of a unified diff (optionally `git log -p` output with commit markers)
+    #[cfg(test)]
+    fn scan_patch(&self, patch: &str) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let mut walker = PatchWalker::default();
+        for line in patch.lines() {
+            self.scan_line(&mut walker, line, &mut findings);
+        }
+        findings
+    }
+}
+
+/// Scan the requested target using git objects, never the working tree.
+/// Allowlisted and baselined findings are left out of the report.
+pub(crate) fn scan(target: &ScanTarget) -> Result<ScanReport> {
+    let mut report = scan_allowlisted(target)?;
+    if let Some(baseline) = crate::secret_baseline::load_baseline()? {
+        let known = baseline.fingerprints();
+        let before = report.findings.len();
+        report.findings.retain(|f| !known.contains(f.fingerprint.as_str()));
+        report.baselined = before - report.findings.len();
+    }
+    Ok(report)
+}
+
+/// Scan without applying the baseline, for `--update-baseline`
+pub(crate) fn scan_allowlisted(target: &ScanTarget) -> Result<ScanReport> {
+    let repo_root = crate::git::get_repo_root()?;
+    let settings = crate::config::load_config()?.secrets;
+    let scanner = Scanner::for_repo(&repo_root, &settings)?;
+    let mut report = scan_raw(target, &scanner)?;
+    let allowlist = Allowlist::new(&repo_root, settings.allow_paths.iter(), settings.allow_rules)?;
+
+    let before = report.findings.len() + report.risky_files.len();
+    report.findings.retain(|f| !allowlist.allows(&f.file, Some(&f.rule_id)));
+    report.risky_files.retain(|file| !allowlist.allows(file, None));
+    report.allowlisted = before - report.findings.len() - report.risky_files.len();
+    Ok(report)
+}
+
+/// Patch output is streamed, so full-history scans don't buffer the whole log
+fn scan_raw(target: &ScanTarget, scanner: &Scanner) -> Result<ScanReport> {
+    let files = target_files(target)?;
+    if files.is_empty() {
+        return Ok(ScanReport::default());
+    }
+
+    let args = patch_args(target)?;
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let findings = scan_stream(scanner, BATCH_LINES, |on_line| for_each_git_line(&args, on_line))?;
+
+    let risky_files = files.iter().filter(|f| scanner.is_risky(f)).cloned().collect();
+
+    Ok(ScanReport {
+        files,
+        risky_files,
+        findings,
+        rule_sources: scanner.rule_sources(),
+        ..Default::default()
+    })
+}
+
+/// Patch lines handed to a worker at once; large enough to amortize channel overhead
+const BATCH_LINES: usize = 4096;
+
+/// Upper bound on worker threads; beyond this git itself is the bottleneck
+const MAX_WORKERS: usize = 8;
+
+/// A run of patch lines plus the walker state at its first line,
+/// so workers can scan batches independently
+struct Batch {
+    seq: usize,
+    walker: PatchWalker,
+    lines: Vec<String>,
+}
+
+/// Scan streamed patch lines on a pool of worker threads. The reader only tracks
+/// file/commit/line state; rule matching happens in the workers. Findings keep
+/// stream order.
+fn scan_stream(
+    scanner: &Scanner,
+    batch_lines: usize,
+    stream: impl FnOnce(&mut dyn FnMut(&str)) -> Result<()>,
+) -> Result<Vec<Finding>> {
+    let workers = std::thread::available_parallelism().map_or(1, |n| n.get().min(MAX_WORKERS));
+    let (sender, receiver) = std::sync::mpsc::sync_channel::<Batch>(workers * 2);
+    let receiver = std::sync::Mutex::new(receiver);
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let next = match receiver.lock() {
+                            Ok(receiver) => receiver.recv(),
+                            Err(_) => break,
+                        };
+                        let Ok(mut batch) = next else {
+                            break;
+                        };
+                        let mut findings = Vec::new();
+                        for line in &batch.lines {
+                            scanner.scan_line(&mut batch.walker, line, &mut findings);
+                        }
+                        done.push((batch.seq, findings));
+                    }
+                    done
+                })
+            })
+            .collect();
+
+        let mut walker = PatchWalker::default();
+        let mut batch = Batch {
+            seq: 0,
+            walker: walker.clone(),
+            lines: Vec::with_capacity(batch_lines),
+        };
+        let streamed = stream(&mut |line: &str| {
+            batch.lines.push(line.to_string());
+            walker.feed(line);
+            if batch.lines.len() >= batch_lines {
+                let next = Batch {
+                    seq: batch.seq + 1,
+                    walker: walker.clone(),
+                    lines: Vec::with_capacity(batch_lines),
+                };
+                // Send fails only if every worker is gone, which join reports below
+                let _ = sender.send(std::mem::replace(&mut batch, next));
+            }
+        });
+        if !batch.lines.is_empty() {
+            let _ = sender.send(batch);
+        }
+        drop(sender);
+
+        let mut results = Vec::new();
+        for handle in handles {
+            let done = handle
+                .join()
+                .map_err(|_| anyhow::anyhow!("Secret scan worker panicked"))?;
+            results.extend(done);
+        }
+        streamed?;
+
+        results.sort_by_key(|(seq, _)| *seq);
+        Ok(results.into_iter().flat_map(|(_, findings)| findings).collect())
+    })
+}
+
+/// `[secrets]` exceptions from the global config and the repository's `.orca.toml`
+struct Allowlist {
+    paths: Option<Gitignore>,
+    rules: Vec<String>,
+}
+
+impl Allowlist {
+    fn new<'a>(
+        repo_root: &Path,
+        paths: impl Iterator<Item = &'a String>,
+        rules: Vec<String>,
+    ) -> Result<Self> {
+        let mut builder = GitignoreBuilder::new(repo_root);
+        let mut any_path = false;
+        for pattern in paths {
+            builder.add_line(None, pattern)?;
+            any_path = true;
+        }
+        let paths = if any_path { Some(builder.build()?) } else { None };
+        Ok(Self { paths, rules })
+    }
+
+    /// Whether findings in `file` (of `rule_id`, when given) are accepted
+    fn allows(&self, file: &str, rule_id: Option<&str>) -> bool {
+        if let Some(rule_id) = rule_id
+            && self.rules.iter().any(|r| r == rule_id)
+        {
+            return true;
+        }
+        self.paths
+            .as_ref()
+            .is_some_and(|g| g.matched_path_or_any_parents(file, false).is_ignore())
+    }
+}
+
+/// Stable across line moves: rule, path and the secret itself (hashed, never stored)
+fn fingerprint(rule_id: &str, file: &str, secret: &str) -> String {
+    let mut hasher = Sha256::new();
+    for part in [rule_id, file, secret] {
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part.as_bytes());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Revision arguments for commit-walking targets
+fn log_revisions(target: &ScanTarget) -> Option<Vec<String>> {
+    match target {
+        ScanTarget::Range(range) => Some(vec![range.clone()]),
+        ScanTarget::History { since } => {
+            let mut revs = vec!["--all".to_string()];
+            if let Some(since) = since {
+                revs.push(format!("^{}", since));
+            }
+            Some(revs)
+        }
+        ScanTarget::Staged | ScanTarget::All => None,
+    }
+}
+
+/// Paths added or modified by the target (deleted files carry no new content)
+fn target_files(target: &ScanTarget) -> Result<Vec<String>> {
+    let mut args: Vec<String> = ["-c", "core.quotePath=false"].map(String::from).to_vec();
+    match target {
+        ScanTarget::Staged => args.extend(["diff", "--cached", "--name-only", "--diff-filter=d"].map(String::from)),
+        ScanTarget::All => args.push("ls-files".to_string()),
+        ScanTarget::Range(_) | ScanTarget::History { .. } => {
+            args.extend(["log", "--name-only", "--diff-filter=d", "--format="].map(String::from));
+            args.extend(log_revisions(target).unwrap_or_default());
+        }
+    }
+
+    let mut files = BTreeSet::new();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    for_each_git_line(&args, |line| {
+        let line = line.trim();
+        if !line.is_empty() {
+            files.insert(line.to_string());
+        }
+    })?;
+    Ok(files.into_iter().collect())
+}
+
+fn patch_args(target: &ScanTarget) -> Result<Vec<String>> {
+    let mut args: Vec<String> = ["-c", "core.quotePath=false"].map(String::from).to_vec();
+    match target {
+        ScanTarget::Staged => args.extend(["diff", "--cached"].map(String::from)),
+        ScanTarget::All => {
+            // Diffing the index against the empty tree yields every tracked line as "added"
+            let empty_tree = run_git(&["hash-object", "-t", "tree", "/dev/null"])?;
+            args.extend(["diff".to_string(), "--cached".to_string(), empty_tree.trim().to_string()]);
+        }
+        ScanTarget::Range(_) | ScanTarget::History { .. } => {
+            args.extend(["log", "-p", "--format=%x01%H%x09%an <%ae>%x09%as"].map(String::from));
+            args.extend(log_revisions(target).unwrap_or_default());
+        }
+    }
+    args.extend(PATCH_ARGS.iter().map(|a| a.to_string()));
+    Ok(args)
+}
+
+/// Whether a path looks like a credentials file that should never be committed
+pub(crate) fn is_risky_file(path: &str) -> bool {
+    let file_name = path.to_lowercase();
+    RISKY_FILES.iter().any(|risky_pattern| {
+        let pattern_lower = risky_pattern.to_lowercase();
+        match pattern_lower.split_once('*') {
+            // Simple wildcard matching
+            Some((prefix, suffix)) => file_name.starts_with(prefix) && file_name.ends_with(suffix),
+            None => file_name.contains(&pattern_lower),
+        }
+    })
+}
+
+/// One added line of a patch with its line number in the new file
+#[derive(Debug, PartialEq, Eq)]
+struct AddedLine<'w, 'l> {
+    commit: Option<&'w CommitInfo>,
+    file: &'w str,
+    line_number: usize,
+    text: &'l str,
+}
+
+/// Walks a `-U0` patch line by line and picks out added lines;
+/// deleted files and binary diffs yield nothing
+#[derive(Default, Clone)]
+struct PatchWalker {
+    commit: Option<CommitInfo>,
+    file: Option<String>,
+    /// Between `diff --git` and the first hunk, where `+++ ` names the file
+    /// (inside a hunk it is an added line starting with `++`)
+    in_header: bool,
+    next_line: usize,
+}
+
+impl PatchWalker {
+    fn feed<'w, 'l>(&'w mut self, line: &'l str) -> Option<AddedLine<'w, 'l>> {
+        if let Some(commit) = line.strip_prefix(COMMIT_MARKER) {
+            self.commit = Some(CommitInfo::parse(commit));
+            self.file = None;
+            self.in_header = false;
+        } else if line.starts_with("diff --git ") {
+            self.file = None;
+            self.in_header = true;
+        } else if let Some(header) = line.strip_prefix("@@ ") {
+            self.in_header = false;
+            self.next_line = parse_hunk_start(header).unwrap_or(0);
+        } else if self.in_header {
+            if let Some(path) = line.strip_prefix("+++ ") {
+                self.file = parse_new_path(path);
+            }
+        } else if let Some(text) = line.strip_prefix('+') {
+            let line_number = self.next_line;
+            self.next_line += 1;
+            return Some(AddedLine {
+                commit: self.commit.as_ref(),
+                file: self.file.as_deref()?,
+                line_number,
+                text,
+            });
+        } else if line.starts_with(' ') {
+            self.next_line += 1;
+        }
+        None
+    }
+}
+
+/// `b/src/main.rs` -> `src/main.rs`; `/dev/null` (deleted file) -> None
+fn parse_new_path(raw: &str) -> Option<String> {
+    let raw = raw.trim_end_matches(['\r', '\t']);
+    if raw == "/dev/null" {
+        return None;
+    }
+    let path = if raw.starts_with('"') {
+        unquote_c_style(raw)
+    } else {
+        raw.to_string()
+    };
+    Some(path.strip_prefix("b/").map(str::to_string).unwrap_or(path))
+}
+
+/// Undo git's C-style path quoting (`"dir/with \"quote\".txt"`)
+fn unquote_c_style(raw: &str) -> String {
+    let inner = raw.trim_matches('"');
+    let mut bytes = Vec::with_capacity(inner.len());
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buf = [0u8; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+        match chars.next() {
+            Some('n') => bytes.push(b'\n'),
+            Some('t') => bytes.push(b'\t'),
+            Some(d @ '0'..='7') => {
+                let mut value = d.to_digit(8).unwrap_or(0);
+                for _ in 0..2 {
+                    if let Some(next) = chars.peek().and_then(|c| c.to_digit(8)) {
+                        value = value * 8 + next;
+                        chars.next();
+                    }
+                }
+                bytes.push(value as u8);
+            }
+            Some(other) => {
+                let mut buf = [0u8; 4];
+                bytes.extend_from_slice(other.encode_utf8(&mut buf).as_bytes());
+            }
+            None => {}
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// `-12,3 +40,2 @@ fn main()` -> 40
+fn parse_hunk_start(header: &str) -> Option<usize> {
+    let new_range = header.split_whitespace().find(|part| part.starts_with('+'))?;
+    new_range[1..].split(',').next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATCH: &str = "\u{1}abc123\tJane Doe <jane@example.com>\t2024-05-01
+diff --git a/config/app.env b/config/app.env
+index 1111111..2222222 100644
+--- a/config/app.env
++++ b/config/app.env
+@@ -3,0 +4,2 @@ HOST=localhost
++PORT=8080
++API_KEY=abcdefghij0123456789xyz
+@@ -10 +12,2 @@ DEBUG=1
+-password=old
++password=hunter2hunter2
++++ not a header
+diff --git a/old.txt b/old.txt
+deleted file mode 100644
+--- a/old.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-api_key=abcdefghij0123456789xyz
+diff --git \"a/dir/na\\\"me.txt\" \"b/dir/na\\\"me.txt\"
+--- /dev/null
++++ \"b/dir/na\\\"me.txt\"
+@@ -0,0 +1 @@
++token: Bearer 9f8e7d6c5b4a39281706f5e4d3c2b1a0ZZ
+";
+
+    #[test]
+    fn reports_added_lines_with_new_file_line_numbers() {
+        let findings = Scanner::new().scan_patch(PATCH);
+        let summary: Vec<(&str, usize, &str)> = findings
+            .iter()
+            .map(|f| (f.file.as_str(), f.line_number, f.pattern_name.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("config/app.env", 5, "API Key"),
+                ("config/app.env", 12, "Password"),
+                ("dir/na\"me.txt", 1, "Bearer Token"),
+            ]
+        );
+        let commit = findings[0].commit.as_ref().unwrap();
+        assert_eq!(commit.hash, "abc123");
+        assert_eq!(commit.author, "Jane Doe <jane@example.com>");
+        assert_eq!(commit.date, "2024-05-01");
+        assert!(findings.iter().all(|f| f.commit.as_ref() == Some(commit)));
+    }
+
+    #[test]
+    fn parallel_batches_match_a_sequential_scan() {
+        let scanner = Scanner::new();
+        let patch = PATCH.repeat(50);
+        let sequential = scanner.scan_patch(&patch);
+        // Tiny batches split hunks and commits across workers
+        let parallel = scan_stream(&scanner, 3, |on_line| {
+            patch.lines().for_each(on_line);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(parallel.len(), 150);
+        assert_eq!(parallel, sequential);
+    }
+
+    #[test]
+    fn parses_hunk_headers_and_paths() {
+        assert_eq!(parse_hunk_start("-12,3 +40,2 @@ fn main()"), Some(40));
+        assert_eq!(parse_hunk_start("-1 +1 @@"), Some(1));
+        assert_eq!(parse_new_path("/dev/null"), None);
+        assert_eq!(parse_new_path("\"b/caf\\303\\251.txt\"").as_deref(), Some("café.txt"));
+    }
+
+    #[test]
+    fn history_walks_all_refs_after_since() {
+        let target = ScanTarget::History {
+            since: Some("v1.0".to_string()),
+        };
+        assert_eq!(log_revisions(&target), Some(vec!["--all".to_string(), "^v1.0".to_string()]));
+        assert_eq!(log_revisions(&ScanTarget::Staged), None);
+    }
+
+    #[test]
+    fn fingerprint_survives_line_moves_and_inline_allow_skips_line() {
+        let moved = PATCH.replace("@@ -3,0 +4,2 @@", "@@ -30,0 +40,2 @@");
+        let before = Scanner::new().scan_patch(PATCH);
+        let after = Scanner::new().scan_patch(&moved);
+        assert_ne!(before[0].line_number, after[0].line_number);
+        assert_eq!(before[0].fingerprint, after[0].fingerprint);
+        assert_ne!(before[0].fingerprint, before[1].fingerprint);
+
+        let allowed = PATCH.replace("hunter2hunter2", "hunter2hunter2 # orca:allow-secret");
+        let findings = Scanner::new().scan_patch(&allowed);
+        assert!(findings.iter().all(|f| f.rule_id != "password-assignment"));
+    }
+
+    #[test]
+    fn allowlist_matches_paths_and_rules() {
+        let paths = ["tests/fixtures/".to_string(), "*.example".to_string()];
+        let allowlist = Allowlist::new(
+            std::path::Path::new("/repo"),
+            paths.iter(),
+            vec!["jwt".to_string()],
+        )
+        .unwrap();
+        assert!(allowlist.allows("tests/fixtures/keys/id_rsa", Some("private-key")));
+        assert!(allowlist.allows("config/.env.example", None));
+        assert!(allowlist.allows("src/auth.rs", Some("jwt")));
+        assert!(!allowlist.allows("src/auth.rs", Some("github-token")));
+        assert!(!allowlist.allows("src/tests/fixtures.rs", None));
+    }
+
+    #[test]
+    fn risky_file_names() {
+        assert!(is_risky_file("deploy/.env.production"));
+        assert!(is_risky_file("certs/server.PEM"));
+        assert!(!is_risky_file("src/environment.rs"));
+    }
+}